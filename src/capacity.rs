@@ -53,6 +53,15 @@ impl CapacityState {
             } => *maximum_concurrent_capacity,
         }
     }
+
+    /// Number of elements that can be pushed to a vector with the given `len` without any allocation.
+    ///
+    /// This is the room left in the current allocations, `current_capacity - len`, for both variants;
+    /// growing beyond the current capacity is never guaranteed to be allocation-free, even when `maximum_concurrent_capacity` is larger.
+    /// Returns zero if `len` is greater than or equal to the current capacity.
+    pub fn guaranteed_room(&self, len: usize) -> usize {
+        self.current_capacity().saturating_sub(len)
+    }
 }
 
 #[cfg(test)]
@@ -87,4 +96,22 @@ mod tests {
             .maximum_concurrent_capacity()
         );
     }
+
+    #[test]
+    fn guaranteed_room() {
+        let fixed = CapacityState::FixedCapacity(42);
+        assert_eq!(fixed.guaranteed_room(0), 42);
+        assert_eq!(fixed.guaranteed_room(40), 2);
+        assert_eq!(fixed.guaranteed_room(42), 0);
+        assert_eq!(fixed.guaranteed_room(50), 0);
+
+        let dynamic = CapacityState::DynamicCapacity {
+            current_capacity: 7,
+            maximum_concurrent_capacity: 42,
+        };
+        assert_eq!(dynamic.guaranteed_room(0), 7);
+        assert_eq!(dynamic.guaranteed_room(5), 2);
+        assert_eq!(dynamic.guaranteed_room(7), 0);
+        assert_eq!(dynamic.guaranteed_room(20), 0);
+    }
 }
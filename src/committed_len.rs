@@ -0,0 +1,25 @@
+/// Length of the prefix of a concurrent pinned vector whose positions are all written; i.e., the committed length.
+///
+/// Consumers of a concurrently filled vector commonly track their own committed length.
+/// This type carries the caller's guarantee that positions `0..len` are written,
+/// which is given once by the unsafe `new`, so that the committed prefix can be read by the safe
+/// [`ConcurrentPinnedVec::occupied_slices`](crate::ConcurrentPinnedVec::occupied_slices).
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+pub struct CommittedLen(usize);
+
+impl CommittedLen {
+    /// Creates the committed length `len` of a concurrent pinned vector.
+    ///
+    /// # Safety
+    ///
+    /// Entries in all positions `0..len` of the concurrent pinned vector that the committed length is used with must be written;
+    /// and they must not be taken out or overwritten while the committed length is in use.
+    pub unsafe fn new(len: usize) -> Self {
+        Self(len)
+    }
+
+    /// Returns the committed length as a number of elements.
+    pub fn get(self) -> usize {
+        self.0
+    }
+}
@@ -1,4 +1,6 @@
-use crate::{PinnedVec, PinnedVecGrowthError};
+use crate::{
+    utils::slice::vec_range_limits, CapacityState, CommittedLen, PinnedVec, PinnedVecGrowthError,
+};
 use core::ops::{Range, RangeBounds};
 
 /// A wrapper for a pinned vector which provides additional guarantees for concurrent programs.
@@ -84,8 +86,42 @@ pub trait ConcurrentPinnedVec<T> {
     /// Therefore, its position will initially be uninitialized; hence, reading the pointer might result in UB.
     unsafe fn get_ptr_mut(&self, index: usize) -> *mut T;
 
+    /// Writes the `value` to the `index`-th position of the vector.
+    ///
+    /// The value is written with `core::ptr::write`; i.e., the prior contents of the position are neither read nor dropped.
+    ///
+    /// # Safety
+    ///
+    /// This method is used to write to the vector, and the position is assumed to be uninitialized.
+    /// Overwriting an already written position does not drop its prior value, which is then leaked.
+    ///
+    /// Further, the caller is responsible for making sure that no other thread concurrently reads from or writes to the same position.
+    unsafe fn write_at(&self, index: usize, value: T) {
+        self.get_ptr_mut(index).write(value)
+    }
+
+    /// Moves the value out of the `index`-th position of the vector, leaving the position logically uninitialized.
+    ///
+    /// The value is read with `core::ptr::read`; i.e., the position is not modified and its value is not dropped by the vector.
+    ///
+    /// # Safety
+    ///
+    /// This method can safely be called if the entry at position `index` is written.
+    ///
+    /// After the call, the position must be treated as uninitialized; taking the same position twice without writing to it in between is UB.
+    /// Further, the caller is responsible for making sure that no other thread concurrently reads from or writes to the same position.
+    unsafe fn take_at(&self, index: usize) -> T {
+        self.get_ptr_mut(index).read()
+    }
+
     /// Returns an iterator of mutable slices to the elements extending over positions `range` of the vector.
     ///
+    /// The range is bounded by the `capacity` of the vector:
+    /// * returns an empty iterator if the range is empty, or if its start is greater than its end;
+    /// * the end of the range is clamped to the `capacity` if it is beyond the capacity.
+    ///
+    /// Implementations can use `utils::slice::vec_range_limits` with `vec_len = Some(capacity())` to satisfy this contract.
+    ///
     /// # Safety
     ///
     /// This method is used to write to the vector.
@@ -98,6 +134,20 @@ pub trait ConcurrentPinnedVec<T> {
     /// Returns an iterator of slices to the elements extending over positions `range` of the vector.
     fn slices<R: RangeBounds<usize>>(&self, range: R) -> <Self::P as PinnedVec<T>>::SliceIter<'_>;
 
+    /// Returns an iterator of slices to the elements extending over the occupied positions `range` of the vector.
+    ///
+    /// This method is meant to be used by consumers tracking their own committed length,
+    /// the positions `0..committed` of which are guaranteed to be written by the creator of the [`CommittedLen`].
+    /// The `range` is clamped to `0..committed`; hence, positions which are not written yet are never read.
+    fn occupied_slices<R: RangeBounds<usize>>(
+        &self,
+        committed: CommittedLen,
+        range: R,
+    ) -> <Self::P as PinnedVec<T>>::SliceIter<'_> {
+        let [a, b] = vec_range_limits(&range, Some(committed.get()));
+        self.slices(a..b)
+    }
+
     // capacity
 
     /// Returns the maximum possible capacity that the vector can concurrently grow to without requiring a `&mut self` reference.
@@ -108,6 +158,17 @@ pub trait ConcurrentPinnedVec<T> {
     /// Returns the current capacity of the vector, which is actually allocated.
     fn capacity(&self) -> usize;
 
+    /// Provides detailed information of capacity state of the concurrent pinned vector.
+    ///
+    /// Returns the `DynamicCapacity` variant with the `current_capacity` obtained by [`ConcurrentPinnedVec::capacity()`]
+    /// and the `maximum_concurrent_capacity` obtained by [`ConcurrentPinnedVec::max_capacity()`].
+    fn capacity_state(&self) -> CapacityState {
+        CapacityState::DynamicCapacity {
+            current_capacity: self.capacity(),
+            maximum_concurrent_capacity: self.max_capacity(),
+        }
+    }
+
     /// Tries to concurrently grow the capacity of the vector to at least `new_capacity`. Returns:
     /// * Ok of the new capacity if succeeds
     /// * Err otherwise.
@@ -148,6 +209,23 @@ pub trait ConcurrentPinnedVec<T> {
     where
         F: Fn() -> T;
 
+    /// Fills the positions `from_len..capacity` with elements created by successively calling the `fill_with` function.
+    ///
+    /// This is convenient just before converting the vector back by `into_inner(capacity)`.
+    ///
+    /// # Safety
+    ///
+    /// This method is used to write to the vector, and positions `from_len..capacity` are assumed to be uninitialized;
+    /// values already written to these positions are not dropped, and hence, are leaked.
+    ///
+    /// Further, the caller is responsible for making sure that no other thread concurrently reads from or writes to these positions.
+    unsafe fn fill_remaining_with<F>(&self, from_len: usize, fill_with: F)
+    where
+        F: Fn() -> T,
+    {
+        self.fill_with(from_len..self.capacity(), fill_with)
+    }
+
     /// Increases the `maximum_capacity` to the `new_maximum_capacity`.
     ///
     /// # Safety
@@ -220,3 +298,130 @@ pub trait ConcurrentPinnedVec<T> {
     /// This method can safely be called if entries in all positions `0..len` are written.
     unsafe fn clear(&mut self, len: usize);
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        pinned_vec_tests::testvec::TestVec, CapacityState, CommittedLen, ConcurrentPinnedVec,
+        IntoConcurrentPinnedVec, PinnedVec,
+    };
+    use alloc::{string::ToString, vec::Vec};
+
+    #[test]
+    fn write_at() {
+        let len = 42;
+        let vec = TestVec::new(len).into_concurrent();
+
+        for i in 0..len {
+            unsafe { vec.write_at(i, i.to_string()) };
+        }
+
+        let values: Vec<_> = unsafe { vec.iter(len) }.cloned().collect();
+        let expected: Vec<_> = (0..len).map(|i| i.to_string()).collect();
+        assert_eq!(values, expected);
+
+        let vec = unsafe { vec.into_inner(len) };
+        assert_eq!(vec.len(), len);
+        assert_eq!(vec.to_vec(), expected);
+    }
+
+    #[test]
+    fn take_at() {
+        let len = 42;
+        let vec = TestVec::new(len).into_concurrent();
+
+        for i in 0..len {
+            unsafe { vec.write_at(i, i.to_string()) };
+        }
+
+        for i in 0..len {
+            let value = unsafe { vec.take_at(i) };
+            assert_eq!(value, i.to_string());
+        }
+
+        for i in 0..len {
+            unsafe { vec.write_at(i, (10 * i).to_string()) };
+        }
+
+        for i in (0..len).rev() {
+            let value = unsafe { vec.take_at(i) };
+            assert_eq!(value, (10 * i).to_string());
+        }
+
+        let vec = unsafe { vec.into_inner(0) };
+        assert!(vec.is_empty());
+    }
+
+    #[test]
+    fn capacity_state() {
+        let mut vec = TestVec::<usize>::new(42).into_concurrent();
+
+        let state = vec.capacity_state();
+        assert_eq!(state.current_capacity(), vec.capacity());
+        assert_eq!(state.maximum_concurrent_capacity(), vec.max_capacity());
+        assert_eq!(
+            state,
+            CapacityState::DynamicCapacity {
+                current_capacity: 42,
+                maximum_concurrent_capacity: 42
+            }
+        );
+
+        unsafe { vec.reserve_maximum_concurrent_capacity(0, 100) };
+        let state = vec.capacity_state();
+        assert_eq!(state.current_capacity(), vec.capacity());
+        assert_eq!(state.maximum_concurrent_capacity(), vec.max_capacity());
+    }
+
+    #[test]
+    fn occupied_slices() {
+        let len = 42;
+        let vec = TestVec::new(len).into_concurrent();
+
+        for i in 0..len {
+            unsafe { vec.write_at(i, i.to_string()) };
+        }
+        let committed = unsafe { CommittedLen::new(30) };
+
+        let values: Vec<_> = vec
+            .occupied_slices(committed, 10..20)
+            .into_iter()
+            .flatten()
+            .cloned()
+            .collect();
+        let expected: Vec<_> = (10..20).map(|i| i.to_string()).collect();
+        assert_eq!(values, expected);
+
+        let count = |range| {
+            vec.occupied_slices(committed, range)
+                .into_iter()
+                .flatten()
+                .count()
+        };
+        assert_eq!(count(0..len), 30);
+        assert_eq!(count(25..35), 5);
+        assert_eq!(count(35..40), 0);
+
+        let _ = unsafe { vec.into_inner(len) };
+    }
+
+    #[test]
+    fn fill_remaining_with() {
+        let capacity = 42;
+        let vec = TestVec::new(capacity).into_concurrent();
+
+        for i in 0..10 {
+            unsafe { vec.write_at(i, i.to_string()) };
+        }
+        unsafe { vec.fill_remaining_with(10, || "x".to_string()) };
+
+        let vec = unsafe { vec.into_inner(capacity) };
+        assert_eq!(vec.len(), capacity);
+        for i in 0..capacity {
+            match i < 10 {
+                true => assert_eq!(vec.get(i), Some(&i.to_string())),
+                false => assert_eq!(vec.get(i).map(|x| x.as_str()), Some("x")),
+            }
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use crate::{ConcurrentPinnedVec, IntoConcurrentPinnedVec};
+use core::ops::Deref;
+
+/// A temporary concurrent view of a pinned vector which is borrowed mutably, created by `IntoConcurrentPinnedVec::as_concurrent`.
+///
+/// The pinned vector is converted into its concurrent wrapper for the lifetime of the view;
+/// and the concurrent wrapper is converted back into the pinned vector, with the length of the view, when the view is dropped.
+/// Therefore, the original vector can be used after the view goes out of scope.
+///
+/// The concurrent pinned vector is accessible by dereferencing the view.
+pub struct ConcurrentRef<'a, T, P>
+where
+    P: IntoConcurrentPinnedVec<T>,
+{
+    vec: &'a mut P,
+    con_vec: Option<P::ConPinnedVec>,
+    len: usize,
+}
+
+impl<'a, T, P> ConcurrentRef<'a, T, P>
+where
+    P: IntoConcurrentPinnedVec<T>,
+{
+    pub(crate) fn new(vec: &'a mut P) -> Self {
+        let len = vec.len();
+        let con_vec = core::mem::replace(vec, P::pseudo_default()).into_concurrent();
+        Self {
+            vec,
+            con_vec: Some(con_vec),
+            len,
+        }
+    }
+
+    /// Returns the length that the pinned vector will have when the view is dropped.
+    ///
+    /// This is the length of the vector when the view is created unless it is updated by `set_len`.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the pinned vector will be empty when the view is dropped.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Sets the length that the pinned vector will have when the view is dropped.
+    ///
+    /// # Safety
+    ///
+    /// Entries in all positions `0..len` of the concurrent pinned vector must be written by the time the view is dropped.
+    pub unsafe fn set_len(&mut self, len: usize) {
+        self.len = len;
+    }
+}
+
+impl<T, P> Deref for ConcurrentRef<'_, T, P>
+where
+    P: IntoConcurrentPinnedVec<T>,
+{
+    type Target = P::ConPinnedVec;
+
+    fn deref(&self) -> &Self::Target {
+        self.con_vec
+            .as_ref()
+            .expect("exists until the view is dropped")
+    }
+}
+
+impl<T, P> Drop for ConcurrentRef<'_, T, P>
+where
+    P: IntoConcurrentPinnedVec<T>,
+{
+    fn drop(&mut self) {
+        if let Some(con_vec) = self.con_vec.take() {
+            // SAFETY: entries in all positions `0..len` are written due to the safety requirement of `set_len`
+            *self.vec = unsafe { con_vec.into_inner(self.len) };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        pinned_vec_tests::testvec::TestVec, ConcurrentPinnedVec, IntoConcurrentPinnedVec, PinnedVec,
+    };
+    use alloc::{
+        string::{String, ToString},
+        vec::Vec,
+    };
+
+    #[test]
+    fn as_concurrent_fill() {
+        let mut vec = TestVec::new(10);
+        vec.push(0.to_string());
+        vec.push(1.to_string());
+        let addr = vec.get_ptr(0).expect("is-some");
+
+        {
+            let mut con_vec = vec.as_concurrent();
+            assert_eq!(con_vec.len(), 2);
+            assert_eq!(con_vec.capacity(), 10);
+            for i in 2..6 {
+                unsafe { con_vec.write_at(i, i.to_string()) };
+            }
+            unsafe { con_vec.set_len(6) };
+        }
+
+        assert_eq!(vec.len(), 6);
+        assert_eq!(vec.get_ptr(0), Some(addr));
+
+        vec.push(6.to_string());
+        let expected: Vec<String> = (0..7).map(|x| x.to_string()).collect();
+        assert_eq!(vec.to_vec(), expected);
+    }
+
+    #[test]
+    fn as_concurrent_unchanged() {
+        let mut vec = TestVec::new(10);
+        for i in 0..4 {
+            vec.push(i);
+        }
+
+        {
+            let con_vec = vec.as_concurrent();
+            assert!(!con_vec.is_empty());
+            assert_eq!(unsafe { con_vec.get(3) }, Some(&3));
+        }
+
+        assert_eq!(vec.to_vec(), &[0, 1, 2, 3]);
+    }
+}
@@ -6,3 +6,17 @@ pub enum PinnedVecGrowthError {
     /// An error which is observed when a pinned vector attempted to increase its capacity while keeping its already added elements pinned in their locations.
     FailedToGrowWhileKeepingElementsPinned,
 }
+
+/// Error describing why a pointer could not be mapped to the index of an element of the pinned vector, returned by `PinnedVec::checked_index_of_ptr`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IndexOfError {
+    /// The vector is empty; hence, no pointer can point to one of its elements.
+    Empty,
+    /// The pointer is below the memory ranges of the elements of the vector.
+    BelowRange,
+    /// The pointer is above the memory range of the elements of the vector, such as a pointer to a position in `len..capacity`;
+    /// or, for fragmented vectors, it is in the gap between the memory ranges of two fragments.
+    AboveRange,
+    /// The pointer is within the memory range of the elements of the vector; however, it does not point to the beginning of an element.
+    Misaligned,
+}
@@ -0,0 +1,108 @@
+use crate::PinnedVec;
+use core::marker::PhantomData;
+
+/// Iterator which removes and yields the elements of a pinned vector satisfying a predicate, returned by `PinnedVec::extract_if`.
+///
+/// The length of the vector is set to zero while the iterator is alive; the retained elements are moved down as the iteration proceeds.
+/// When the iterator is dropped, the unvisited elements are moved down next to the retained ones and the length of the vector is restored.
+pub(crate) struct ExtractIf<'a, T, P, F>
+where
+    P: PinnedVec<T> + ?Sized,
+    F: FnMut(&mut T) -> bool,
+{
+    vec: &'a mut P,
+    pred: F,
+    idx: usize,
+    num_extracted: usize,
+    old_len: usize,
+    phantom: PhantomData<T>,
+}
+
+impl<'a, T, P, F> ExtractIf<'a, T, P, F>
+where
+    P: PinnedVec<T> + ?Sized,
+    F: FnMut(&mut T) -> bool,
+{
+    pub(crate) fn new(vec: &'a mut P, pred: F) -> Self {
+        let old_len = vec.len();
+        // SAFETY: length is restored when the iterator is dropped; elements are leaked rather than double dropped if it is forgotten
+        unsafe { vec.set_len(0) };
+        Self {
+            vec,
+            pred,
+            idx: 0,
+            num_extracted: 0,
+            old_len,
+            phantom: PhantomData,
+        }
+    }
+
+    fn ptr(&mut self, index: usize) -> *mut T {
+        self.vec
+            .get_ptr_mut(index)
+            .expect("index is within capacity")
+    }
+
+    /// Moves the element at position `index` `num_extracted` positions to the left, filling the gap of the extracted elements.
+    ///
+    /// Both positions are accessed through a single `get2_mut` borrow, for which the length is temporarily restored:
+    /// all positions `0..old_len` hold bitwise copies of valid elements, the vacated ones being copies of moved or extracted elements;
+    /// and the length is set back to zero before anything else is done with the vector.
+    fn move_down(&mut self, index: usize) {
+        if self.num_extracted > 0 {
+            // SAFETY: the references created by `get2_mut` are only used for the bitwise copy below
+            unsafe { self.vec.set_len(self.old_len) };
+            if let Some((dst, src)) = self.vec.get2_mut(index - self.num_extracted, index) {
+                // SAFETY: `dst` is a vacated position of an extracted element, and `src` is vacated after the copy
+                unsafe { core::ptr::copy_nonoverlapping(src as *const T, dst as *mut T, 1) };
+            }
+            // SAFETY: restores the length of the vector while the iterator is alive
+            unsafe { self.vec.set_len(0) };
+        }
+    }
+}
+
+impl<T, P, F> Iterator for ExtractIf<'_, T, P, F>
+where
+    P: PinnedVec<T> + ?Sized,
+    F: FnMut(&mut T) -> bool,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.idx < self.old_len {
+            let i = self.idx;
+            let ptr = self.ptr(i);
+            // SAFETY: elements at positions `idx..old_len` are initialized and not yet visited
+            let extract = (self.pred)(unsafe { &mut *ptr });
+            self.idx += 1;
+            match extract {
+                true => {
+                    self.num_extracted += 1;
+                    // SAFETY: the position is considered vacated from now on
+                    return Some(unsafe { core::ptr::read(ptr) });
+                }
+                false => self.move_down(i),
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.old_len - self.idx))
+    }
+}
+
+impl<T, P, F> Drop for ExtractIf<'_, T, P, F>
+where
+    P: PinnedVec<T> + ?Sized,
+    F: FnMut(&mut T) -> bool,
+{
+    fn drop(&mut self) {
+        for i in self.idx..self.old_len {
+            self.move_down(i);
+        }
+        // SAFETY: positions `0..old_len - num_extracted` hold the retained and unvisited elements
+        unsafe { self.vec.set_len(self.old_len - self.num_extracted) };
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{ConcurrentPinnedVec, PinnedVec};
+use crate::{ConcurrentPinnedVec, ConcurrentRef, PinnedVec};
 
 /// A pinned vector which can be wrapped into a concurrent pinned vector.
 pub trait IntoConcurrentPinnedVec<T>: PinnedVec<T> {
@@ -17,4 +17,18 @@ pub trait IntoConcurrentPinnedVec<T>: PinnedVec<T> {
     fn into_concurrent_filled_with<F>(self, fill_with: F) -> Self::ConPinnedVec
     where
         F: Fn() -> T;
+
+    /// Creates a temporary concurrent view of the pinned vector without giving up its ownership.
+    ///
+    /// The vector is converted into its concurrent wrapper by `into_concurrent`, and it is converted back when the view is dropped;
+    /// the vector can then be used as before.
+    /// Memory locations of the elements do not change during these conversions.
+    ///
+    /// See [`ConcurrentRef`] for details.
+    fn as_concurrent(&mut self) -> ConcurrentRef<'_, T, Self>
+    where
+        Self: Sized,
+    {
+        ConcurrentRef::new(self)
+    }
 }
@@ -13,10 +13,15 @@
 #![no_std]
 
 extern crate alloc;
+#[cfg(any(test, feature = "std"))]
+extern crate std;
 
 mod capacity;
+mod committed_len;
 mod concurrent_pinned_vec;
+mod concurrent_ref;
 mod errors;
+mod extract_if;
 mod into_concurrent_pinned_vec;
 mod pinned_vec;
 /// Tests methods to validate pinned element guarantees of an implementing type.
@@ -25,8 +30,10 @@ pub mod pinned_vec_tests;
 pub mod utils;
 
 pub use capacity::CapacityState;
+pub use committed_len::CommittedLen;
 pub use concurrent_pinned_vec::ConcurrentPinnedVec;
-pub use errors::PinnedVecGrowthError;
+pub use concurrent_ref::ConcurrentRef;
+pub use errors::{IndexOfError, PinnedVecGrowthError};
 pub use into_concurrent_pinned_vec::IntoConcurrentPinnedVec;
 pub use pinned_vec::PinnedVec;
-pub use pinned_vec_tests::test_pinned_vec;
+pub use pinned_vec_tests::{test_pinned_vec, test_pinned_vec_generic};
@@ -1,17 +1,25 @@
-use crate::CapacityState;
+use crate::{
+    extract_if::ExtractIf, utils::slice::vec_range_limits, CapacityState, IndexOfError,
+    PinnedVecGrowthError,
+};
+use alloc::vec::Vec;
 use core::{
     cmp::Ordering,
-    ops::{Index, IndexMut, RangeBounds},
+    mem::MaybeUninit,
+    ops::{Index, IndexMut, Range, RangeBounds},
 };
 use orx_pseudo_default::PseudoDefault;
 
 /// Trait for vector representations differing from `std::vec::Vec` by the following:
 ///
 /// => memory location of an element already pushed to the collection never changes unless any of the following `mut` methods is called:
-/// * `remove`, `pop`,
-/// * `insert`,
+/// * `remove`, `pop`, `retain_mut`, `retain_collect_removed`, `compact`, `dedup_consecutive_by_ptr`, `keep_last`, `extract_if`, `swap_remove_by`, `swap_remove_front`,
+/// * `insert`, `insert_from_slice`, `binary_insert`, `move_to_front`,
 /// * `clear`, `truncate`.
 ///
+/// Methods in the first two groups might move elements which remain in the vector;
+/// hence, they must not be used when elements hold references to each other, unless these references are fixed afterwards.
+///
 /// In other words,
 ///
 /// => growth methods `push` or `extend_from_slice` do <ins>not</ins> change memory locations of already added elements.
@@ -94,6 +102,176 @@ pub trait PinnedVec<T>:
     /// * *O(f)* for [SplitVec](https://crates.io/crates/orx-split-vec) where f << n is the number of fragments.
     fn index_of_ptr(&self, element_ptr: *const T) -> Option<usize>;
 
+    /// Returns the index of the `element_ptr` pointing to an element of the vec.
+    ///
+    /// This is the panicking counterpart of `index_of_ptr`, useful when the pointer is required to belong to this vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `element_ptr` does not point to an element of this vector.
+    fn require_index_of_ptr(&self, element_ptr: *const T) -> usize {
+        self.index_of_ptr(element_ptr)
+            .expect("pointer does not belong to this pinned vec")
+    }
+
+    /// Returns the index of the `element_ptr` pointing to an element of the vec;
+    /// or an error describing why the pointer does not point to an element of the vec, which is helpful in diagnosing dangling or foreign pointers.
+    ///
+    /// * `IndexOfError::Empty` if the vector is empty;
+    /// * `IndexOfError::BelowRange` if the pointer is below the memory ranges of all elements;
+    /// * `IndexOfError::AboveRange` if the pointer is above the memory range of the elements, or in a gap between two fragments;
+    /// * `IndexOfError::Misaligned` if the pointer is within the memory range of the elements, but not at the beginning of an element.
+    ///
+    /// The default implementation scans the slices yielded by `as_slices`; hence, its complexity is *O(f)* where f is the number of fragments.
+    fn checked_index_of_ptr(&self, element_ptr: *const T) -> Result<usize, IndexOfError> {
+        let size = core::mem::size_of::<T>();
+        let addr = element_ptr as usize;
+
+        let mut begin_index = 0;
+        let mut min_begin = None;
+        for slice in self.as_slices() {
+            let range = slice.as_ptr_range();
+            let (begin, end) = (range.start as usize, range.end as usize);
+            if begin <= addr && addr < end {
+                let offset = addr - begin;
+                return match offset % size {
+                    0 => Ok(begin_index + offset / size),
+                    _ => Err(IndexOfError::Misaligned),
+                };
+            }
+            begin_index += slice.len();
+            min_begin = Some(min_begin.map_or(begin, |x: usize| x.min(begin)));
+        }
+
+        match min_begin {
+            None => Err(IndexOfError::Empty),
+            Some(min_begin) if addr < min_begin => Err(IndexOfError::BelowRange),
+            Some(_) => Err(IndexOfError::AboveRange),
+        }
+    }
+
+    /// Returns the index of the element that `ptr` points to; returns None if the pointer does not point to an element of the vector.
+    ///
+    /// This is an alias of `index_of_ptr`, and is the companion of `ptr_at` for bookkeeping structures mapping stable element addresses to indices:
+    /// `index_lookup(ptr_at(i)?) == Some(i)` holds for every `i` in `0..len`.
+    fn index_lookup(&self, ptr: *const T) -> Option<usize> {
+        self.index_of_ptr(ptr)
+    }
+
+    /// Returns a pointer to the position `index` of the vector; returns None if `index` is out of `capacity`.
+    ///
+    /// This is an alias of `get_ptr`, and is the companion of `index_lookup`.
+    /// Note that a pointer is returned for positions in `len..capacity` as well, which do not hold an element;
+    /// `index_lookup` of such a pointer returns None.
+    fn ptr_at(&self, index: usize) -> Option<*const T> {
+        self.get_ptr(index)
+    }
+
+    /// Returns the index of the first element of the vector satisfying the predicate `pred`;
+    /// returns None if none of the elements satisfies it.
+    ///
+    /// Unlike `index_of` which relies on the memory address of the element, this method searches by value in *O(n)* time.
+    fn position<F>(&self, pred: F) -> Option<usize>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.iter().position(pred)
+    }
+
+    /// Returns the index of the last element of the vector satisfying the predicate `pred`;
+    /// returns None if none of the elements satisfies it.
+    ///
+    /// The elements are searched starting from the end of the vector in *O(n)* time.
+    fn rposition<F>(&self, pred: F) -> Option<usize>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.iter_rev().position(pred).map(|i| self.len() - 1 - i)
+    }
+
+    /// Returns the number of elements of the vector satisfying the predicate `pred`.
+    ///
+    /// Defaults to `self.iter().filter(pred).count()`; however, implementations might override it to process the elements fragment by fragment.
+    fn count_matching<F>(&self, mut pred: F) -> usize
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.iter().filter(|x| pred(x)).count()
+    }
+
+    /// Returns a reference to the minimum element of the vector; returns None if the vector is empty.
+    ///
+    /// If several elements are equally minimum, the first element is returned.
+    ///
+    /// The method is not named `min` since, for implementors which are themselves `Ord`, `vec.min()` would resolve to `Ord::min` taking `self` by value.
+    fn min_element(&self) -> Option<&T>
+    where
+        T: Ord,
+    {
+        self.iter().min()
+    }
+
+    /// Returns a reference to the maximum element of the vector; returns None if the vector is empty.
+    ///
+    /// If several elements are equally maximum, the last element is returned.
+    ///
+    /// Similar to `min_element`, the method is not named `max` to avoid being shadowed by `Ord::max` on implementors which are `Ord`.
+    fn max_element(&self) -> Option<&T>
+    where
+        T: Ord,
+    {
+        self.iter().max()
+    }
+
+    /// Returns a reference to the element of the vector that gives the minimum value of the `key` function;
+    /// returns None if the vector is empty.
+    ///
+    /// If several elements are equally minimum, the first element is returned.
+    fn min_by_key<B, F>(&self, mut key: F) -> Option<&T>
+    where
+        B: Ord,
+        F: FnMut(&T) -> B,
+    {
+        self.iter().min_by_key(|x| key(x))
+    }
+
+    /// Returns a reference to the element of the vector that gives the maximum value of the `key` function;
+    /// returns None if the vector is empty.
+    ///
+    /// If several elements are equally maximum, the last element is returned.
+    fn max_by_key<B, F>(&self, mut key: F) -> Option<&T>
+    where
+        B: Ord,
+        F: FnMut(&T) -> B,
+    {
+        self.iter().max_by_key(|x| key(x))
+    }
+
+    /// Folds every element of the vector into an accumulator by applying `f` to the references of the elements, in order.
+    ///
+    /// Fragmented implementations can override this method to fold fragment by fragment for better locality.
+    fn fold_ref<B, F>(&self, init: B, f: F) -> B
+    where
+        F: FnMut(B, &T) -> B,
+    {
+        self.iter().fold(init, f)
+    }
+
+    /// Folds the elements of the vector into an accumulator by applying the fallible `f` to the references of the elements, in order;
+    /// returns the first error, if any, without visiting the remaining elements.
+    ///
+    /// Fragmented implementations can override this method to fold fragment by fragment for better locality.
+    fn try_fold_ref<B, E, F>(&self, init: B, mut f: F) -> Result<B, E>
+    where
+        F: FnMut(B, &T) -> Result<B, E>,
+    {
+        let mut acc = init;
+        for x in self.iter() {
+            acc = f(acc, x)?;
+        }
+        Ok(acc)
+    }
+
     /// Appends an element to the back of a collection and returns a pointer to its position in the vector.
     fn push_get_ptr(&mut self, value: T) -> *const T;
 
@@ -107,10 +285,34 @@ pub trait PinnedVec<T>:
     ///
     /// Therefore, the caller is responsible for making sure that the obtained pointers are still
     /// valid before accessing through the pointers.
+    ///
+    /// When the vector is kept borrowed while the elements are accessed, `element_refs` is the safe alternative.
     unsafe fn iter_ptr<'v, 'i>(&'v self) -> impl Iterator<Item = *const T> + 'i
     where
         T: 'i;
 
+    /// Returns an iterator of references to the elements of the vec, which is identical to `iter`.
+    ///
+    /// This is the safe alternative to `iter_ptr` for the common case where the vector is kept borrowed while the elements are accessed;
+    /// the lifetime of the references is bound to the lifetime of `&self`.
+    fn element_refs<'a>(&'a self) -> impl Iterator<Item = &'a T> + 'a
+    where
+        T: 'a,
+    {
+        self.iter()
+    }
+
+    /// Returns an iterator of the pointers to the elements of the vec together with the number of pointers it yields, which is equal to `len`.
+    ///
+    /// The count allows the caller to pre-size buffers before consuming the iterator.
+    /// Unlike `iter_ptr`, the iterator borrows the vector; the pointers are valid as long as the vector is not mutated.
+    fn iter_ptr_len<'a>(&'a self) -> (impl Iterator<Item = *const T> + 'a, usize)
+    where
+        T: 'a,
+    {
+        (self.iter().map(|x| x as *const T), self.len())
+    }
+
     /// Creates a reverse iterator of the pointers to the elements of the vec, starting from the last element to the first.
     ///
     /// # Safety
@@ -125,6 +327,44 @@ pub trait PinnedVec<T>:
     where
         T: 'i;
 
+    /// Creates an iterator of the pointers to the elements of the vec within the given `range`.
+    ///
+    /// The range is clamped to the bounds of the vector; hence, the iterator is empty if the range is out of bounds.
+    ///
+    /// The default implementation skips and takes over `iter_ptr`; implementations might override it to directly jump to the beginning of the range.
+    ///
+    /// # Safety
+    ///
+    /// The implementor guarantees that the pointers are valid and belong to the elements of the vector.
+    /// However, the lifetime of the pointers might be extended by the caller;
+    /// i.e., it is not bound to the lifetime of `&self`.
+    ///
+    /// Therefore, the caller is responsible for making sure that the obtained pointers are still
+    /// valid before accessing through the pointers.
+    unsafe fn iter_ptr_over<'v, 'i, R: RangeBounds<usize>>(
+        &'v self,
+        range: R,
+    ) -> impl Iterator<Item = *const T> + 'i
+    where
+        T: 'i,
+    {
+        let [a, b] = vec_range_limits(&range, Some(self.len()));
+        self.iter_ptr().skip(a).take(b - a)
+    }
+
+    /// Returns whether or not the vector contains an element which is equal to the given `value`.
+    ///
+    /// Unlike `contains_reference`, this method uses value equality rather than memory address;
+    /// hence, it returns true for a clone of an element of the vector as well.
+    ///
+    /// Defaults to a linear search over `iter`; hence, its complexity is *O(n)* where n is the vector length.
+    fn contains(&self, value: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter().any(|x| x == value)
+    }
+
     /// Returns whether or not of the `element` with the given reference belongs to this vector.
     /// In other words, returns whether or not the reference to the `element` is valid.
     ///
@@ -167,6 +407,26 @@ pub trait PinnedVec<T>:
     ///   since `clear` requires a `mut` reference.
     fn clear(&mut self);
 
+    /// Clears the vector, removing all values, and returns the number of removed elements; i.e., the length of the vector prior to clearing.
+    ///
+    /// This is equivalent to `clear`, and is convenient for instrumentation.
+    fn clear_count(&mut self) -> usize {
+        let len = self.len();
+        self.clear();
+        len
+    }
+
+    /// Clears the vector, removing all values, and then releases the allocated capacity beyond `keep_capacity`.
+    ///
+    /// Elements are dropped exactly as in `clear` before any memory is released.
+    ///
+    /// The default implementation only clears the vector, which is appropriate for fixed capacity vectors that cannot release memory.
+    /// Dynamic vectors are expected to override it to release their unused capacity.
+    fn clear_and_shrink(&mut self, keep_capacity: usize) {
+        let _ = keep_capacity;
+        self.clear();
+    }
+
     /// Returns the total number of elements the vector can hold without reallocating.
     fn capacity(&self) -> usize;
 
@@ -175,6 +435,79 @@ pub trait PinnedVec<T>:
     /// This information contains the current capacity which can be obtained by [`PinnedVec::capacity()`] method and extends with additional useful information.
     fn capacity_state(&self) -> CapacityState;
 
+    /// Returns the number of bytes allocated by the vector for its elements, including the unused capacity.
+    ///
+    /// Defaults to `capacity() * size_of::<T>()`, which is the sum over fragments for fragmented vectors.
+    /// Memory used for meta information, such as per-fragment bookkeeping, is excluded unless the implementation overrides this method to account for it.
+    fn allocated_bytes(&self) -> usize {
+        self.capacity() * core::mem::size_of::<T>()
+    }
+
+    /// Returns the hard upper bound on the capacity of the vector; i.e., the vector can never grow beyond this number of elements.
+    ///
+    /// Defaults to `usize::MAX`, which is appropriate for vectors that can grow without a structural limit.
+    /// Fixed capacity vectors are expected to override it to return their fixed capacity,
+    /// while dynamic vectors with a structural maximum are expected to return this maximum.
+    fn capacity_bound(&self) -> usize {
+        usize::MAX
+    }
+
+    /// Reserves capacity for at least `additional` more elements to be pushed to the vector.
+    ///
+    /// This is a hint for dynamic vectors to allocate in advance and reduce the number of allocations during subsequent growth.
+    /// The default implementation is a no-op, which is appropriate for fixed capacity vectors.
+    ///
+    /// Memory locations of the elements already in the vector must not change.
+    fn reserve(&mut self, additional: usize) {
+        let _ = additional;
+    }
+
+    /// Reserves room for at least `additional` more elements to be pushed to the vector concurrently,
+    /// and returns the resulting maximum concurrent capacity of the vector.
+    ///
+    /// This is useful for producers which push in bursts to grow the vector in advance.
+    /// Defaults to calling `reserve(additional)` and returning `capacity_state().maximum_concurrent_capacity()`.
+    /// The returned value might be less than `len + additional` if the vector cannot grow, such as fixed capacity vectors.
+    fn reserve_concurrent(&mut self, additional: usize) -> usize {
+        self.reserve(additional);
+        self.capacity_state().maximum_concurrent_capacity()
+    }
+
+    /// Increases the capacity of the vector at least up to the `min_capacity`:
+    /// * returns Ok of the new capacity if succeeds,
+    /// * returns Err otherwise.
+    ///
+    /// During growth:
+    ///
+    /// * length of the vector is increased to its new capacity;
+    /// * the elements in the range `len..capacity` are filled with the values
+    ///   obtained by repeatedly calling the function `fill`.
+    ///
+    /// The default implementation is appropriate for fixed capacity vectors:
+    /// it fails if `min_capacity` exceeds the current capacity, and fills the remaining capacity otherwise.
+    /// Dynamic vectors are expected to override this method.
+    ///
+    /// Memory locations of the elements already in the vector are not changed in either case.
+    fn grow_and_initialize<F>(
+        &mut self,
+        min_capacity: usize,
+        mut fill: F,
+    ) -> Result<usize, PinnedVecGrowthError>
+    where
+        F: FnMut() -> T,
+    {
+        let capacity = self.capacity();
+        match min_capacity <= capacity {
+            true => {
+                while self.len() < capacity {
+                    self.push(fill());
+                }
+                Ok(capacity)
+            }
+            false => Err(PinnedVecGrowthError::FailedToGrowWhileKeepingElementsPinned),
+        }
+    }
+
     /// Clones and appends all elements in a slice to the Vec.
     ///
     /// Iterates over `other`, clones each element, and then appends it to this vec. The other slice is traversed in-order.
@@ -182,10 +515,53 @@ pub trait PinnedVec<T>:
     where
         T: Clone;
 
+    /// Clones and appends all elements in a slice to the Vec, reserving the required capacity up front.
+    ///
+    /// Calls `reserve(other.len())` once before appending the elements with `extend_from_slice`.
+    /// Therefore, dynamic vectors allocate at most once rather than growing incrementally,
+    /// while the capacity of fixed capacity vectors is checked once.
+    fn extend_from_slice_reserved(&mut self, other: &[T])
+    where
+        T: Clone,
+    {
+        self.reserve(other.len());
+        self.extend_from_slice(other);
+    }
+
     /// Returns a reference to an element with the given `index` returns None if the index is out of bounds.
     fn get(&self, index: usize) -> Option<&T>;
     /// Returns a mutable reference to an element with the given `index` returns None if the index is out of bounds.
     fn get_mut(&mut self, index: usize) -> Option<&mut T>;
+    /// Returns mutable references to the two elements at indices `a` and `b` at the same time.
+    ///
+    /// Returns None if `a == b` or if either of the indices is out of bounds.
+    ///
+    /// The references are obtained from the disjoint views of `split_at_mut` at the larger of the indices.
+    fn get2_mut(&mut self, a: usize, b: usize) -> Option<(&mut T, &mut T)> {
+        let len = self.len();
+        match a != b && a < len && b < len {
+            true => {
+                let (first, mut second) = self.split_at_mut(a.max(b));
+                let x = nth_in_slices_mut(first, a.min(b))?;
+                let y = second.next()?.first_mut()?;
+                Some(match a < b {
+                    true => (x, y),
+                    false => (y, x),
+                })
+            }
+            false => None,
+        }
+    }
+    /// Returns references to the elements at the given `indices` at the same time.
+    ///
+    /// Returns None if any of the indices is out of bounds; duplicate indices are allowed.
+    fn get_many<const N: usize>(&self, indices: [usize; N]) -> Option<[&T; N]> {
+        let len = self.len();
+        match indices.iter().all(|i| *i < len) {
+            true => Some(indices.map(|i| &self[i])),
+            false => None,
+        }
+    }
     /// Returns a reference to an element without doing bounds checking.
     ///
     /// For a safe alternative see `get`.
@@ -210,6 +586,35 @@ pub trait PinnedVec<T>:
     /// Returns a reference to the last element of the vector; returns None if the vector is empty.
     fn last(&self) -> Option<&T>;
 
+    /// Returns a reference to the first `N` elements of the vector as an array.
+    ///
+    /// Returns None if:
+    /// * the length of the vector is less than `N`,
+    /// * or the first `N` elements do not lie within a single contiguous fragment of the vector.
+    ///
+    /// Contiguous vectors always return Some when `len >= N`.
+    fn first_chunk<const N: usize>(&self) -> Option<&[T; N]> {
+        match self.len() >= N {
+            true => single_slice_as_array(self.slices(0..N)),
+            false => None,
+        }
+    }
+
+    /// Returns a reference to the last `N` elements of the vector as an array.
+    ///
+    /// Returns None if:
+    /// * the length of the vector is less than `N`,
+    /// * or the last `N` elements do not lie within a single contiguous fragment of the vector.
+    ///
+    /// Contiguous vectors always return Some when `len >= N`.
+    fn last_chunk<const N: usize>(&self) -> Option<&[T; N]> {
+        let len = self.len();
+        match len >= N {
+            true => single_slice_as_array(self.slices((len - N)..len)),
+            false => None,
+        }
+    }
+
     /// Returns a reference to the first element of the vector without bounds checking.
     ///
     /// For a safe alternative see `first`.
@@ -242,6 +647,57 @@ pub trait PinnedVec<T>:
     /// # Panics
     /// Panics if `index >= len`.
     fn insert(&mut self, index: usize, element: T);
+    /// Inserts the `value` into its sorted position in the vector, found by `binary_search`, and returns the index of the inserted element.
+    ///
+    /// The vector is expected to be sorted; if it is not, the insertion position is unspecified and meaningless.
+    /// If there exist elements equal to the `value`, it might be inserted at any position among them.
+    ///
+    /// Since this method uses `insert`, memory locations of the elements to the right of the inserted element are changed.
+    fn binary_insert(&mut self, value: T) -> usize
+    where
+        T: Ord,
+    {
+        let index = match self.binary_search(&value) {
+            Ok(i) | Err(i) => i,
+        };
+        self.insert(index, value);
+        index
+    }
+
+    /// Inserts clones of the elements of the `slice` at position `index` within the vector, shifting all elements after it to the right.
+    ///
+    /// The elements after `index` are shifted only once regardless of the length of the slice;
+    /// hence, this is more efficient than inserting the elements one by one.
+    ///
+    /// Similar to `insert`, memory locations of the first `index` elements do not change;
+    /// while the elements to the right of the inserted elements are moved.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    fn insert_from_slice(&mut self, index: usize, slice: &[T])
+    where
+        T: Clone,
+    {
+        let len = self.len();
+        assert!(index <= len, "index is out of bounds");
+        for x in slice {
+            self.push(x.clone());
+        }
+        rotate_right(self, index, len + slice.len(), slice.len());
+    }
+    /// Moves the element at position `index` to the front of the vector, shifting the elements at positions `0..index` one position to the right.
+    ///
+    /// This is equivalent to removing the element at `index` and inserting it at position 0, which is useful for LRU-style reordering.
+    /// Memory locations of the elements at positions `0..=index` change, while the locations of the elements after `index` do not.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    fn move_to_front(&mut self, index: usize) {
+        assert!(index < self.len(), "index is out of bounds");
+        rotate_right(self, 0, index + 1, 1);
+    }
     /// Removes and returns the element at position index within the vector, shifting all elements after it to the left.
     ///
     /// # Panics
@@ -250,6 +706,44 @@ pub trait PinnedVec<T>:
     fn remove(&mut self, index: usize) -> T;
     /// Removes the last element from a vector and returns it, or None if it is empty.
     fn pop(&mut self) -> Option<T>;
+    /// Removes the first element satisfying the predicate `pred` and returns it; returns None if no element satisfies it.
+    ///
+    /// The removed element is replaced by the last element of the vector.
+    /// Therefore, this method only changes the memory location of the last element, which is moved to the position of the removed element;
+    /// memory locations of all other elements do not change.
+    /// However, it does not preserve the order of the elements.
+    fn swap_remove_by<F>(&mut self, pred: F) -> Option<T>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let index = self.position(pred)?;
+        let last = self.len() - 1;
+        self.swap(index, last);
+        self.pop()
+    }
+    /// Removes and returns the first element of the vector, or None if it is empty.
+    ///
+    /// The removed element is replaced by the last element of the vector; hence, it does not preserve the order of the elements.
+    /// This method disturbs only the first position and the former last position:
+    /// the last element is moved to index 0, while memory locations of all other elements do not change.
+    fn swap_remove_front(&mut self) -> Option<T> {
+        let last = self.len().checked_sub(1)?;
+        self.swap(0, last);
+        self.pop()
+    }
+    /// Removes and returns the last element of the vector if the predicate `f` returns true for it;
+    /// returns None if the vector is empty or the predicate returns false, leaving the vector unchanged.
+    ///
+    /// Since only the last element might be removed, memory locations of the remaining elements do not change.
+    fn pop_if<F>(&mut self, f: F) -> Option<T>
+    where
+        F: FnOnce(&T) -> bool,
+    {
+        match self.last().map(f) {
+            Some(true) => self.pop(),
+            _ => None,
+        }
+    }
     /// Swaps two elements in the slice.
     ///
     /// If `a` equals to `b`, it's guaranteed that elements won't change value.
@@ -259,6 +753,92 @@ pub trait PinnedVec<T>:
     /// * a - The index of the first element
     /// * b - The index of the second element.
     fn swap(&mut self, a: usize, b: usize);
+    /// Replaces the element at the given `index` with the `value` and returns the old element.
+    ///
+    /// The value is written in place; hence, unlike `remove` followed by `insert`, no element is moved
+    /// and memory locations of all elements remain unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    fn replace(&mut self, index: usize, value: T) -> T {
+        let element = self.get_mut(index).expect("index is out of bounds");
+        core::mem::replace(element, value)
+    }
+    /// Swaps two elements in the vector if both indices are in bounds and returns true;
+    /// returns false without mutating the vector otherwise.
+    ///
+    /// This is the non-panicking counterpart of `swap`.
+    fn try_swap(&mut self, a: usize, b: usize) -> bool {
+        let len = self.len();
+        match a < len && b < len {
+            true => {
+                self.swap(a, b);
+                true
+            }
+            false => false,
+        }
+    }
+    /// Swaps all elements of the vector with those in `other`, element-wise.
+    ///
+    /// The values are exchanged in place; hence, memory locations of the elements of the vector do not change.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `other` differs from the length of the vector.
+    fn swap_with_slice(&mut self, other: &mut [T]) {
+        assert_eq!(
+            self.len(),
+            other.len(),
+            "lengths of the vector and the slice must be equal"
+        );
+        for (a, b) in self.iter_mut().zip(other.iter_mut()) {
+            core::mem::swap(a, b);
+        }
+    }
+    /// Swaps the elements within the range `a` with those within the range `b`, element-wise.
+    ///
+    /// Both ranges are borrowed at once by `get_disjoint_mut` and the elements are swapped one by one;
+    /// therefore, unlike slice-based swaps, the ranges are allowed to span multiple fragments of a fragmented vector.
+    /// The values are exchanged in place; hence, memory locations of the elements of the vector do not change.
+    ///
+    /// # Panics
+    ///
+    /// Panics if:
+    /// * lengths of the ranges are not equal,
+    /// * the ranges overlap, or
+    /// * either of the ranges is out of bounds.
+    fn swap_ranges_crossing(&mut self, a: Range<usize>, b: Range<usize>) {
+        assert_eq!(a.len(), b.len(), "lengths of the ranges must be equal");
+        assert!(
+            a.is_empty() || a.end <= b.start || b.end <= a.start,
+            "ranges must not overlap"
+        );
+        let len = self.len();
+        assert!(a.end <= len && b.end <= len, "range is out of bounds");
+
+        if let Some([a, b]) = self.get_disjoint_mut([a, b]) {
+            let a = a.flat_map(|s| s.iter_mut());
+            let b = b.flat_map(|s| s.iter_mut());
+            for (x, y) in a.zip(b) {
+                core::mem::swap(x, y);
+            }
+        }
+    }
+
+    /// Fills the elements in the given `range` with clones of the `value`.
+    ///
+    /// The range is clamped to the bounds of the vector; and the elements are overwritten in place using `slices_mut`,
+    /// hence, memory locations of the elements do not change.
+    fn fill_range<R: RangeBounds<usize>>(&mut self, range: R, value: T)
+    where
+        T: Clone,
+    {
+        let [a, b] = vec_range_limits(&range, Some(self.len()));
+        for s in self.slices_mut(a..b) {
+            s.fill(value.clone());
+        }
+    }
     /// Shortens the vector, keeping the first `len` elements and dropping
     /// the rest.
     ///
@@ -266,10 +846,304 @@ pub trait PinnedVec<T>:
     /// effect.
     fn truncate(&mut self, len: usize);
 
+    /// Shortens the vector, keeping the first `len` elements and dropping the rest; and returns the number of dropped elements.
+    ///
+    /// This is equivalent to `truncate(len)`; hence, memory locations of the first `len` elements do not change.
+    ///
+    /// If `len` is greater than or equal to the vector's current length, this has no effect and returns zero.
+    fn truncate_count(&mut self, len: usize) -> usize {
+        let num_dropped = self.len().saturating_sub(len);
+        self.truncate(len);
+        num_dropped
+    }
+
+    /// Shortens the vector, keeping the first `len` elements and dropping the rest,
+    /// with the guarantee that the elements are dropped in descending index order.
+    ///
+    /// This is useful when the elements hold references to prior elements of the vector,
+    /// since the referencing elements are dropped before the referenced ones.
+    ///
+    /// If `len` is greater than the vector's current length, this has no
+    /// effect.
+    fn truncate_reverse(&mut self, len: usize) {
+        while self.len() > len {
+            let _ = self.pop();
+        }
+    }
+
+    /// Shortens the vector, keeping the first `n` elements and dropping the rest.
+    ///
+    /// This is equivalent to `truncate(n)`; hence, memory locations of the first `n` elements do not change.
+    ///
+    /// If `n` is greater than the vector's current length, this has no effect.
+    fn truncate_to_keep_first(&mut self, n: usize) {
+        self.truncate(n);
+    }
+
+    /// Shortens the vector, keeping the last `n` elements and dropping the rest.
+    ///
+    /// Since the elements are removed from the front, the retained elements at positions `len - n..len` are moved to positions `0..n`;
+    /// hence, whenever any element is dropped, none of the retained elements keeps its memory location.
+    ///
+    /// If `n` is greater than or equal to the vector's current length, this has no effect.
+    fn keep_last(&mut self, n: usize) {
+        let len = self.len();
+        if n < len {
+            let num_dropped = len - n;
+            for i in 0..n {
+                self.swap(i, num_dropped + i);
+            }
+            self.truncate(n);
+        }
+    }
+
+    /// Returns a mutable reference to the element at the given `index`;
+    /// if the vector is not long enough, first pushes elements created by `fill` until the length of the vector becomes `index + 1`.
+    ///
+    /// Since the vector only grows at the end, memory locations of the existing elements do not change.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than or equal to the `capacity_bound` of the vector;
+    /// i.e., when a fixed capacity vector is requested to grow beyond its capacity.
+    fn get_or_extend_with<F>(&mut self, index: usize, mut fill: F) -> &mut T
+    where
+        F: FnMut() -> T,
+    {
+        if index >= self.len() {
+            assert!(
+                index < self.capacity_bound(),
+                "cannot extend to index {} since it exceeds the capacity bound {} of the pinned vector",
+                index,
+                self.capacity_bound()
+            );
+            while self.len() <= index {
+                self.push(fill());
+            }
+        }
+        &mut self[index]
+    }
+
+    /// Resizes the vector in-place so that its length becomes `new_len`.
+    ///
+    /// If `new_len` is greater than the current length, the vector is extended by the difference,
+    /// with each additional slot filled with a clone of `value`.
+    /// Otherwise, the vector is truncated to `new_len`.
+    ///
+    /// In either case, memory locations of the elements that are kept do not change.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `new_len` is greater than the `capacity_bound` of the vector;
+    /// i.e., when a fixed capacity vector is requested to grow beyond its capacity.
+    fn resize(&mut self, new_len: usize, value: T)
+    where
+        T: Clone,
+    {
+        let len = self.len();
+        if new_len > len {
+            assert!(
+                new_len <= self.capacity_bound(),
+                "cannot resize to {} since it exceeds the capacity bound {} of the pinned vector",
+                new_len,
+                self.capacity_bound()
+            );
+            for _ in len..new_len {
+                self.push(value.clone());
+            }
+        } else {
+            self.truncate(new_len);
+        }
+    }
+
+    /// Clones the elements in the given `range` and appends them to the end of the vector.
+    ///
+    /// The range is clamped to the bounds of the vector, similar to `to_vec_range`.
+    ///
+    /// Since the elements are only pushed to the end, memory locations of the existing elements do not change.
+    fn extend_from_within<R: RangeBounds<usize>>(&mut self, range: R)
+    where
+        T: Clone,
+    {
+        let [a, b] = vec_range_limits(&range, Some(self.len()));
+        self.reserve(b - a);
+        for i in a..b {
+            let value = self[i].clone();
+            self.push(value);
+        }
+    }
+
+    /// Retains only the elements specified by the predicate `f`, passing a mutable reference to it.
+    ///
+    /// In other words, removes all elements `e` such that `f(&mut e)` returns false.
+    /// This method operates in place, visiting each element exactly once in the original order,
+    /// and preserves the order of the retained elements.
+    ///
+    /// Memory locations of the elements prior to the first removed element do not change;
+    /// each retained element after it is moved to the left by the number of elements removed before it.
+    fn retain_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        let len = self.len();
+        let mut num_retained = 0;
+        for i in 0..len {
+            if f(&mut self[i]) {
+                if num_retained != i {
+                    self.swap(num_retained, i);
+                }
+                num_retained += 1;
+            }
+        }
+        self.truncate(num_retained);
+    }
+
+    /// Moves all live elements, for which `is_live` returns true, to the front of the vector preserving their order,
+    /// drops the remaining elements and returns the new length of the vector, which is the number of live elements.
+    ///
+    /// This is equivalent to `retain_mut` followed by `len`.
+    /// Memory locations of the elements prior to the first dead element do not change;
+    /// each live element after it is moved to the position equal to the number of live elements preceding it.
+    fn compact<F>(&mut self, mut is_live: F) -> usize
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.retain_mut(|x| is_live(x));
+        self.len()
+    }
+
+    /// Removes consecutive elements whose `key` pointer is equal to that of the previous element, keeping the first of each run.
+    ///
+    /// This is useful for self-referential collections where identity is address based;
+    /// such as removing consecutive elements which refer to the same target, where `key` returns the stored pointer of an element.
+    ///
+    /// Memory locations of the elements prior to the first removed duplicate do not change;
+    /// each retained element after it, which is the first element of a later run, is moved to the left by the number of duplicates removed before it.
+    fn dedup_consecutive_by_ptr<F>(&mut self, key: F)
+    where
+        F: Fn(&T) -> *const (),
+    {
+        let mut prev = None;
+        self.retain_mut(|x| {
+            let current = key(x);
+            let retain = prev != Some(current);
+            prev = Some(current);
+            retain
+        });
+    }
+
+    /// Retains only the elements specified by the predicate `pred` and returns the original indices of the removed elements in ascending order.
+    ///
+    /// This is useful to keep external data which is aligned with the positions of the vector in sync.
+    ///
+    /// Memory locations of the elements prior to the first removed index do not change;
+    /// a retained element originally at position `i` is moved to position `i - k`, where `k` is the number of returned indices which are less than `i`.
+    fn retain_collect_removed<F>(&mut self, mut pred: F) -> Vec<usize>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut removed = Vec::new();
+        let mut index = 0;
+        self.retain_mut(|x| {
+            let retain = pred(x);
+            if !retain {
+                removed.push(index);
+            }
+            index += 1;
+            retain
+        });
+        removed
+    }
+
+    /// Returns an iterator which removes and yields the elements for which the predicate `pred` returns true,
+    /// passing a mutable reference to each element.
+    ///
+    /// The elements are visited exactly once in the original order, and the order of the retained elements is preserved.
+    /// The removal is lazy: elements are only visited as the iterator is consumed.
+    /// If the iterator is dropped before being fully consumed, the unvisited elements are retained.
+    ///
+    /// Memory locations of the elements prior to the first extracted element do not change.
+    /// As the iterator is consumed, each retained element after it is moved to the left by the number of elements extracted before it;
+    /// when the iterator is dropped, the unvisited elements are moved to the left by the total number of extracted elements.
+    ///
+    /// The length of the vector is set to zero until the iterator is dropped; hence, if the iterator is leaked, the elements are leaked as well.
+    fn extract_if<'a, F>(&'a mut self, pred: F) -> impl Iterator<Item = T> + 'a
+    where
+        T: 'a,
+        F: FnMut(&mut T) -> bool + 'a,
+    {
+        ExtractIf::new(self, pred)
+    }
+
     /// Returns an iterator to elements of the vector.
     fn iter(&self) -> Self::Iter<'_>;
     /// Returns an iterator of mutable references to elements of the vector.
     fn iter_mut(&mut self) -> Self::IterMut<'_>;
+    /// Returns an iterator yielding the elements of the vector together with their indices, `(index, &element)`.
+    ///
+    /// Defaults to `self.iter().enumerate()`; however, implementations might override it to produce the indices more cheaply.
+    fn iter_indexed<'a>(&'a self) -> impl Iterator<Item = (usize, &'a T)> + 'a
+    where
+        T: 'a,
+    {
+        self.iter().enumerate()
+    }
+
+    /// Consumes the vector and returns an iterator yielding the owned elements in reverse order, from the last to the first.
+    ///
+    /// Defaults to collecting the elements into a standard `Vec` and iterating it backwards;
+    /// however, implementations might override it to avoid the intermediate allocation.
+    fn into_iter_rev(self) -> impl Iterator<Item = T>
+    where
+        Self: Sized,
+    {
+        self.into_iter().collect::<Vec<_>>().into_iter().rev()
+    }
+
+    /// Returns an iterator yielding the elements of the vector from the beginning as long as the predicate `pred` holds;
+    /// the iteration stops at the first element for which `pred` returns false.
+    ///
+    /// Defaults to `self.iter().take_while(pred)`; however, implementations might override it to stop scanning fragments early.
+    fn iter_while<'a, F>(&'a self, mut pred: F) -> impl Iterator<Item = &'a T> + 'a
+    where
+        T: 'a,
+        F: FnMut(&T) -> bool + 'a,
+    {
+        self.iter().take_while(move |x| pred(x))
+    }
+
+    /// Returns an iterator yielding the pairs of adjacent elements of the vector, `(&self[i], &self[i + 1])` for `i` in `0..len-1`.
+    ///
+    /// The pairs are created over the element iterator rather than the slices;
+    /// hence, in fragmented vectors, the pair of the last element of a fragment and the first element of the next fragment is yielded as well.
+    /// Yields no pairs if the vector has less than two elements.
+    fn iter_pairs<'a>(&'a self) -> impl Iterator<Item = (&'a T, &'a T)> + 'a
+    where
+        T: 'a,
+    {
+        self.iter().zip(self.iter().skip(1))
+    }
+
+    /// Returns an iterator yielding mutable references to the elements of the vector together with their indices, `(index, &mut element)`.
+    ///
+    /// Defaults to `self.iter_mut().enumerate()`; however, implementations might override it to produce the indices more cheaply.
+    fn iter_mut_indexed<'a>(&'a mut self) -> impl Iterator<Item = (usize, &'a mut T)> + 'a
+    where
+        T: 'a,
+    {
+        self.iter_mut().enumerate()
+    }
+
+    /// Returns an iterator yielding mutable references to the first `n` elements of the vector;
+    /// or to all elements if `n` is greater than the length of the vector.
+    ///
+    /// Defaults to `self.iter_mut().take(n)`; however, fragmented implementations might override it to stop at the fragment containing the `n`-th element.
+    fn iter_mut_take<'a>(&'a mut self, n: usize) -> impl Iterator<Item = &'a mut T> + 'a
+    where
+        T: 'a,
+    {
+        self.iter_mut().take(n)
+    }
     /// Returns a reversed back-to-front iterator to elements of the vector.
     fn iter_rev(&self) -> Self::IterRev<'_>;
     /// Returns a reversed back-to-front iterator mutable references to elements of the vector.
@@ -287,16 +1161,388 @@ pub trait PinnedVec<T>:
     /// * returns an iterator yielding ordered slices that forms the required range when chained.
     fn slices_mut<R: RangeBounds<usize>>(&mut self, range: R) -> Self::SliceMutIter<'_>;
 
+    /// Returns an iterator yielding the minimal set of ordered slices which forms the entire vector when chained:
+    ///
+    /// * yields a single slice for contiguous vectors, and one slice per fragment for fragmented vectors;
+    /// * yields no slices if the vector is empty.
+    fn as_slices<'a>(&'a self) -> impl Iterator<Item = &'a [T]> + 'a
+    where
+        T: 'a,
+    {
+        self.slices(..).into_iter().filter(|s| !s.is_empty())
+    }
+
+    /// Returns an iterator yielding the raw bytes of each of the slices yielded by `as_slices`;
+    /// i.e., one byte slice for contiguous vectors, and one byte slice per fragment for fragmented vectors.
+    ///
+    /// The concatenation of the byte slices is the raw byte representation of the entire vector,
+    /// which allows writing the vector to an IO sink or hashing it without copying the elements.
+    #[cfg(feature = "bytemuck")]
+    fn as_byte_slices<'a>(&'a self) -> impl Iterator<Item = &'a [u8]> + 'a
+    where
+        T: bytemuck::Pod + 'a,
+    {
+        self.as_slices().map(bytemuck::cast_slice)
+    }
+
+    /// Returns an iterator yielding the index ranges of the contiguous fragments of the vector, which together cover `0..len`:
+    ///
+    /// * yields the single range `0..len` for contiguous vectors, and one range per fragment for fragmented vectors;
+    /// * yields no ranges if the vector is empty.
+    ///
+    /// The ranges are ordered, non-overlapping and non-empty; and they correspond to the slices yielded by `as_slices`.
+    fn fragment_boundaries<'a>(&'a self) -> impl Iterator<Item = Range<usize>> + 'a
+    where
+        T: 'a,
+    {
+        self.as_slices().scan(0, |begin, s| {
+            let range = *begin..(*begin + s.len());
+            *begin = range.end;
+            Some(range)
+        })
+    }
+
+    /// Returns a pair of iterators over `chunk_size` elements of the vector at a time, starting at the beginning of the vector:
+    ///
+    /// * the first iterator yields only the full chunks, each of which has length exactly `chunk_size`;
+    /// * the second iterator yields the remainders which could not form a full chunk.
+    ///
+    /// Chunks never span multiple fragments: each fragment of a fragmented vector is chunked separately with `slice::chunks_exact`;
+    /// hence, each fragment might leave a non-empty remainder, which are yielded in order by the second iterator.
+    /// For contiguous vectors, the second iterator yields at most one slice, which is the `remainder` of `slice::chunks_exact`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero.
+    fn chunks_exact<'a>(
+        &'a self,
+        chunk_size: usize,
+    ) -> (
+        impl Iterator<Item = &'a [T]> + 'a,
+        impl Iterator<Item = &'a [T]> + 'a,
+    )
+    where
+        T: 'a,
+    {
+        assert!(chunk_size != 0, "chunk size must be non-zero");
+        let chunks = self
+            .as_slices()
+            .flat_map(move |s| s.chunks_exact(chunk_size));
+        let remainders = self
+            .as_slices()
+            .map(move |s| s.chunks_exact(chunk_size).remainder())
+            .filter(|s| !s.is_empty());
+        (chunks, remainders)
+    }
+
+    /// Returns an iterator over `chunk_size` elements of the vector at a time, starting at the end of the vector.
+    ///
+    /// The chunks are slices and do not overlap. Similar to `slice::rchunks`, if `chunk_size` does not divide the length,
+    /// the last yielded chunk, which belongs to the beginning of the vector, will not have length `chunk_size`.
+    ///
+    /// Chunks never span multiple fragments: each fragment of a fragmented vector is chunked separately, starting from its end;
+    /// hence, shorter chunks might also be yielded at the fragment boundaries.
+    /// For contiguous vectors, the yielded chunks are identical to those of `slice::rchunks`.
+    ///
+    /// The fragments are visited from the back without allocating: the last fragment of the not yet visited range `0..end` is found by `slices(0..end)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero.
+    fn rchunks<'a>(&'a self, chunk_size: usize) -> impl Iterator<Item = &'a [T]> + 'a
+    where
+        T: 'a,
+    {
+        assert!(chunk_size != 0, "chunk size must be non-zero");
+        let mut end = self.len();
+        let fragments_rev = core::iter::from_fn(move || {
+            let last = self
+                .slices(0..end)
+                .into_iter()
+                .filter(|s| !s.is_empty())
+                .last()?;
+            end -= last.len();
+            Some(last)
+        });
+        fragments_rev.flat_map(move |s| s.rchunks(chunk_size))
+    }
+
+    /// Returns an iterator yielding the minimal set of ordered and disjoint mutable slices which forms the entire vector when chained:
+    ///
+    /// * yields a single slice for contiguous vectors, and one slice per fragment for fragmented vectors;
+    /// * yields no slices if the vector is empty.
+    fn as_mut_slices<'a>(&'a mut self) -> impl Iterator<Item = &'a mut [T]> + 'a
+    where
+        T: 'a,
+    {
+        self.slices_mut(..).into_iter().filter(|s| !s.is_empty())
+    }
+
+    /// Divides the vector into two views at the index `mid`, returned as iterators of slices:
+    ///
+    /// * the first one yields slices forming the range `0..mid`;
+    /// * the second one yields slices forming the range `mid..len`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > len`.
+    fn split_at(&self, mid: usize) -> (Self::SliceIter<'_>, Self::SliceIter<'_>) {
+        assert!(mid <= self.len(), "mid is out of bounds");
+        (self.slices(0..mid), self.slices(mid..self.len()))
+    }
+
+    /// Divides the vector into two disjoint mutable views at the index `mid`, returned as iterators of mutable slices:
+    ///
+    /// * the first one yields mutable slices forming the range `0..mid`;
+    /// * the second one yields mutable slices forming the range `mid..len`.
+    ///
+    /// Both views are derived from a single `slices_mut` borrow of the entire vector, the fragment containing `mid` being split by `slice::split_at_mut`.
+    /// Since a `SliceMutIter` can only be created by `slices_mut` and two of them cannot borrow the vector at the same time,
+    /// the views are returned as iterators: the slices of the first view are collected, which allocates only if `mid` is beyond the first fragment;
+    /// the second view lazily continues the borrowed slices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > len`.
+    fn split_at_mut<'a>(
+        &'a mut self,
+        mid: usize,
+    ) -> (
+        impl Iterator<Item = &'a mut [T]> + 'a,
+        impl Iterator<Item = &'a mut [T]> + 'a,
+    )
+    where
+        T: 'a,
+    {
+        assert!(mid <= self.len(), "mid is out of bounds");
+        let mut slices = self.as_mut_slices();
+        let mut first = (None, Vec::new());
+        let mut straddling = None;
+        let mut remaining = mid;
+        while remaining > 0 {
+            let slice = slices.next().expect("slices cover the vector");
+            let slice = match slice.len() <= remaining {
+                true => slice,
+                false => {
+                    let (left, right) = slice.split_at_mut(remaining);
+                    straddling = Some(right);
+                    left
+                }
+            };
+            remaining -= slice.len();
+            match first.0 {
+                None => first.0 = Some(slice),
+                Some(_) => first.1.push(slice),
+            }
+        }
+        (
+            first.0.into_iter().chain(first.1),
+            straddling.into_iter().chain(slices),
+        )
+    }
+
+    /// Returns the view on the first `n` elements of the vector as an iterator of slices;
+    /// the view covers the entire vector if `n` is greater than the length of the vector.
+    fn first_n(&self, n: usize) -> Self::SliceIter<'_> {
+        self.slices(0..n.min(self.len()))
+    }
+
+    /// Returns the view on the last `n` elements of the vector as an iterator of slices;
+    /// the view covers the entire vector if `n` is greater than the length of the vector.
+    fn last_n(&self, n: usize) -> Self::SliceIter<'_> {
+        let len = self.len();
+        self.slices(len.saturating_sub(n)..len)
+    }
+
+    /// Returns mutable views on each of the given `ranges` as iterators of mutable slices.
+    ///
+    /// Returns None if:
+    /// * any of the ranges is out of bounds; i.e., its end is greater than the length of the vector or its start is greater than its end,
+    /// * or any two of the ranges overlap; empty ranges never overlap.
+    ///
+    /// Similar to `split_at_mut`, all views are derived from a single `slices_mut` borrow of the entire vector:
+    /// each fragment is split by `slice::split_at_mut` at the boundaries of the ranges intersecting it.
+    fn get_disjoint_mut<'a, const N: usize>(
+        &'a mut self,
+        ranges: [Range<usize>; N],
+    ) -> Option<[impl Iterator<Item = &'a mut [T]> + 'a; N]>
+    where
+        T: 'a,
+    {
+        let len = self.len();
+        let in_bounds = ranges.iter().all(|r| r.start <= r.end && r.end <= len);
+        let overlaps = |a: &Range<usize>, b: &Range<usize>| {
+            !a.is_empty() && !b.is_empty() && a.start < b.end && b.start < a.end
+        };
+        let disjoint = ranges
+            .iter()
+            .enumerate()
+            .all(|(i, a)| ranges.iter().skip(i + 1).all(|b| !overlaps(a, b)));
+
+        if !(in_bounds && disjoint) {
+            return None;
+        }
+
+        let mut order: [usize; N] = core::array::from_fn(|i| i);
+        order.sort_by_key(|i| ranges[*i].start);
+
+        let mut views: [Vec<&'a mut [T]>; N] = core::array::from_fn(|_| Vec::new());
+        let mut begin = 0;
+        for fragment in self.as_mut_slices() {
+            let end = begin + fragment.len();
+            let (mut rest, mut offset) = (fragment, begin);
+            for i in order {
+                let (a, b) = (ranges[i].start.max(offset), ranges[i].end.min(end));
+                if a < b {
+                    let (_, right) = core::mem::take(&mut rest).split_at_mut(a - offset);
+                    let (view, right) = right.split_at_mut(b - a);
+                    views[i].push(view);
+                    (rest, offset) = (right, b);
+                }
+            }
+            begin = end;
+        }
+        Some(views.map(Vec::into_iter))
+    }
+
+    /// Clones the elements in the required `range` into a new `Vec`.
+    ///
+    /// The range is clamped to the bounds of the vector; hence:
+    ///
+    /// * returns an empty vec if the range is out of bounds;
+    /// * returns the elements of the in-bounds part of the range otherwise.
+    fn to_vec_range<R: RangeBounds<usize>>(&self, range: R) -> Vec<T>
+    where
+        T: Clone,
+    {
+        let [a, b] = vec_range_limits(&range, Some(self.len()));
+        let mut vec = Vec::with_capacity(b - a);
+        for s in self.slices(a..b) {
+            vec.extend_from_slice(s);
+        }
+        vec
+    }
+
+    /// Clones all elements of the vector into a new `Vec`.
+    fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.clone_into_std_vec()
+    }
+
+    /// Clones all elements of the vector into a new standard `Vec`.
+    ///
+    /// The elements are copied slice by slice with `extend_from_slice` over `slices(..)`;
+    /// hence, a contiguous vector is copied in one bulk operation while a fragmented vector is copied once per fragment,
+    /// rather than walking the elements one by one.
+    fn clone_into_std_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        let mut vec = Vec::with_capacity(self.len());
+        for s in self.slices(..) {
+            vec.extend_from_slice(s);
+        }
+        vec
+    }
+
     /// Returns a pointer to the `index`-th element of the vector.
     ///
     /// Returns `None` if `index`-th position does not belong to the vector; i.e., if `index` is out of `capacity`.
+    ///
+    /// **Note that this method returns a pointer for every `index < capacity()`, including the uninitialized positions in `len..capacity`.**
+    /// Such a pointer does not point to an element; it must not be read, and `index_of_ptr` does not recognize it as an element of the vector.
+    /// Use `get_initialized_ptr` to obtain pointers only to the existing elements.
     fn get_ptr(&self, index: usize) -> Option<*const T>;
 
+    /// Returns a pointer to the `index`-th element of the vector; returns `None` if `index` is out of bounds, i.e., if `index >= len`.
+    ///
+    /// Unlike `get_ptr`, this method never returns a pointer to an uninitialized position in `len..capacity`;
+    /// hence, the returned pointer always points to an existing element of the vector.
+    fn get_initialized_ptr(&self, index: usize) -> Option<*const T> {
+        match index < self.len() {
+            true => self.get_ptr(index),
+            false => None,
+        }
+    }
+
+    /// Validates that the pinned element guarantee holds for the `recorded` pairs of `(index, ptr)`;
+    /// i.e., that each `index` is still in bounds and the element at this position is still located at `ptr`.
+    ///
+    /// Returns `Err(index)` for the first recorded pair which does not hold; returns `Ok(())` if all pairs hold.
+    ///
+    /// This allows downstream crates to assert the pinned element guarantees of their implementations in their own tests:
+    /// addresses of elements can be recorded before a mutation, such as a `push` or `truncate`, and validated afterwards.
+    fn validate_pinned_invariant(&self, recorded: &[(usize, *const T)]) -> Result<(), usize> {
+        match recorded
+            .iter()
+            .find(|(index, ptr)| self.get_initialized_ptr(*index) != Some(*ptr))
+        {
+            Some((index, _)) => Err(*index),
+            None => Ok(()),
+        }
+    }
+
     /// Returns a mutable pointer to the `index`-th element of the vector.
     ///
     /// Returns `None` if `index`-th position does not belong to the vector; i.e., if `index` is out of `capacity`.
     fn get_ptr_mut(&mut self, index: usize) -> Option<*mut T>;
 
+    /// Returns the pointer span of the elements within the `range` as a tuple of the pointer to the first element and the number of elements.
+    ///
+    /// The span is valid only when all elements of the range are in one contiguous fragment of the vector.
+    /// Therefore, returns None if:
+    /// * the range is out of bounds; i.e., `range.start > range.end` or `range.end > len`, which includes empty ranges starting beyond `len`,
+    /// * or the elements of the range do not lie within a single contiguous fragment of the vector.
+    ///
+    /// Contiguous vectors always return Some for in-bounds ranges.
+    /// An empty range with `range.start <= len` returns `Some((ptr, 0))` where `ptr` is dangling and must not be dereferenced.
+    fn try_get_ptr_range(&self, range: Range<usize>) -> Option<(*const T, usize)> {
+        let len = self.len();
+        match range.start <= range.end && range.end <= len {
+            false => None,
+            true if range.is_empty() => Some((core::ptr::NonNull::dangling().as_ptr(), 0)),
+            true => {
+                let mut slices = self.slices(range).into_iter().filter(|s| !s.is_empty());
+                match (slices.next(), slices.next()) {
+                    (Some(slice), None) => Some((slice.as_ptr(), slice.len())),
+                    _ => None,
+                }
+            }
+        }
+    }
+
+    /// Hints the processor to prefetch the element at position `index` into the cache.
+    ///
+    /// This is an opt-in performance hook for traversals which know the next index to be visited in advance,
+    /// such as linked structures built on top of the pinned vector.
+    /// It does not affect the vector in any way, and it is a no-op if `index` is out of `capacity`.
+    /// Further, it is a no-op on targets other than `x86_64` and `x86` with SSE.
+    fn prefetch(&self, index: usize) {
+        if let Some(ptr) = self.get_ptr(index) {
+            #[cfg(target_arch = "x86_64")]
+            #[allow(unused_unsafe)]
+            // SAFETY: prefetching is only a hint; the pointer is not dereferenced
+            unsafe {
+                use core::arch::x86_64::{_mm_prefetch, _MM_HINT_T0};
+                _mm_prefetch::<_MM_HINT_T0>(ptr as *const i8);
+            }
+            #[cfg(all(target_arch = "x86", target_feature = "sse"))]
+            #[allow(unused_unsafe)]
+            // SAFETY: prefetching is only a hint; the pointer is not dereferenced
+            unsafe {
+                use core::arch::x86::{_mm_prefetch, _MM_HINT_T0};
+                _mm_prefetch::<_MM_HINT_T0>(ptr as *const i8);
+            }
+            #[cfg(not(any(
+                target_arch = "x86_64",
+                all(target_arch = "x86", target_feature = "sse")
+            )))]
+            let _ = ptr;
+        }
+    }
+
     /// Forces the length of the vector to `new_len`.
     ///
     /// This is a low-level operation that maintains none of the normal invariants of the type.
@@ -307,6 +1553,17 @@ pub trait PinnedVec<T>:
     /// - The elements at `old_len..new_len` must be initialized.
     unsafe fn set_len(&mut self, new_len: usize);
 
+    /// Returns an iterator of the remaining spare capacity of the vector as `MaybeUninit<T>` slots;
+    /// i.e., the slots at positions `len..capacity`, in order and possibly spanning multiple fragments.
+    ///
+    /// The returned slots can be used to fill the vector with data before marking the data as initialized
+    /// using the `set_len` method.
+    /// After writing to the first `n` slots, `set_len(len + n)` must be called to make the written elements part of the vector;
+    /// otherwise, the written values are neither visible through the vector nor dropped.
+    fn spare_capacity_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut MaybeUninit<T>> + 'a
+    where
+        T: 'a;
+
     /// Binary searches vector slice with a comparator function.
     ///
     /// The comparator function `f` should return an order code that indicates whether its argument is Less, Equal or Greater the desired target.
@@ -343,6 +1600,118 @@ pub trait PinnedVec<T>:
     where
         F: FnMut(&T) -> Ordering;
 
+    /// Binary searches the `range` of the vector with a comparator function.
+    ///
+    /// This method behaves exactly as `binary_search_by` except that only the elements within the `range` are searched,
+    /// which is useful when the bounds of the searched value are already known.
+    /// The range is clamped to the bounds of the vector.
+    ///
+    /// Returned indices are absolute; i.e., they are positions in the entire vector rather than relative to the beginning of the `range`:
+    ///
+    /// * Result::Ok containing the index of a matching element if found;
+    /// * Result::Err containing the index where a matching element could be inserted while maintaining sorted order otherwise,
+    ///   which lies within the clamped range.
+    fn binary_search_in<R, F>(&self, range: R, mut f: F) -> Result<usize, usize>
+    where
+        R: RangeBounds<usize>,
+        F: FnMut(&T) -> Ordering,
+    {
+        let [mut begin, mut end] = vec_range_limits(&range, Some(self.len()));
+        while begin < end {
+            let mid = begin + (end - begin) / 2;
+            match f(&self[mid]) {
+                Ordering::Less => begin = mid + 1,
+                Ordering::Greater => end = mid,
+                Ordering::Equal => return Ok(mid),
+            }
+        }
+        Err(begin)
+    }
+
+    /// Binary searches this vector for the `search_value` using a branchless search loop.
+    /// If the vector is not sorted, the returned result is unspecified and meaningless.
+    ///
+    /// The result has the same meaning as that of `binary_search`;
+    /// however, if there are multiple matches, the returned index might differ from the one returned by `binary_search`.
+    ///
+    /// The search loop always runs *log(n)* iterations and selects the next half with a conditional move rather than a branch,
+    /// which is often faster for large vectors of small `Copy` elements such as `u32` or `u64`.
+    /// The branchless loop runs over contiguous vectors; it falls back to `binary_search` for fragmented vectors.
+    fn binary_search_branchless(&self, search_value: &T) -> Result<usize, usize>
+    where
+        T: Ord + Copy,
+    {
+        let mut slices = self.as_slices();
+        match (slices.next(), slices.next()) {
+            (None, _) => Err(0),
+            (Some(slice), None) => {
+                let mut base = 0;
+                let mut size = slice.len();
+                while size > 1 {
+                    let half = size / 2;
+                    let mid = base + half;
+                    base = if slice[mid] > *search_value {
+                        base
+                    } else {
+                        mid
+                    };
+                    size -= half;
+                }
+                match slice[base].cmp(search_value) {
+                    Ordering::Equal => Ok(base),
+                    Ordering::Less => Err(base + 1),
+                    Ordering::Greater => Err(base),
+                }
+            }
+            _ => self.binary_search(search_value),
+        }
+    }
+
+    /// Returns true if the elements of the vector are sorted in non-decreasing order; false otherwise.
+    ///
+    /// Empty vectors and vectors with a single element are considered to be sorted.
+    fn is_sorted(&self) -> bool
+    where
+        T: PartialOrd,
+    {
+        self.iter().zip(self.iter().skip(1)).all(|(a, b)| a <= b)
+    }
+
+    /// Binary searches this vector for the `search_value`, asserting in debug builds that the vector is sorted.
+    ///
+    /// When `debug_assertions` are enabled, it first checks whether the vector is sorted with `is_sorted`, which takes linear time;
+    /// otherwise, it is identical to `binary_search`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `debug_assertions` are enabled and the vector is not sorted.
+    fn binary_search_checked(&self, search_value: &T) -> Result<usize, usize>
+    where
+        T: Ord,
+    {
+        debug_assert!(
+            self.is_sorted(),
+            "binary search is called on a pinned vector which is not sorted"
+        );
+        self.binary_search(search_value)
+    }
+
+    /// Binary searches this vector with a comparator function `f` exactly as `binary_search_by`;
+    /// and returns the result together with the number of comparisons performed, `(result, num_comparisons)`.
+    ///
+    /// This is useful for profiling searches and verifying the expected *O(log n)* behavior of implementations, especially the fragmented ones.
+    fn search_by<F>(&self, mut f: F) -> (Result<usize, usize>, usize)
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        let mut num_comparisons = 0;
+        let result = self.binary_search_by(|x| {
+            num_comparisons += 1;
+            f(x)
+        });
+        (result, num_comparisons)
+    }
+
     /// Binary searches this vector for the `search_value`.
     /// If the vector is not sorted, the returned result is unspecified and
     /// meaningless.
@@ -422,6 +1791,51 @@ pub trait PinnedVec<T>:
         self.binary_search_by(|k| f(k).cmp(b))
     }
 
+    /// Returns the index of the partition point according to the given predicate `pred`;
+    /// i.e., the index of the first element for which the predicate returns false.
+    ///
+    /// The vector is assumed to be partitioned according to the predicate;
+    /// i.e., all elements for which the predicate returns true are at the beginning of the vector,
+    /// and all elements for which it returns false are at the end.
+    /// If the vector is not partitioned, the returned result is unspecified and meaningless.
+    fn partition_point<F>(&self, mut pred: F) -> usize
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.binary_search_by(|x| match pred(x) {
+            true => Ordering::Less,
+            false => Ordering::Greater,
+        })
+        .unwrap_or_else(|i| i)
+    }
+
+    /// Returns the index of the first element which is not less than the `value` in this sorted vector;
+    /// `len` if all elements are less than the `value`.
+    ///
+    /// Unlike `binary_search` which returns an arbitrary match among equal elements,
+    /// this is the deterministic first position at which the `value` can be inserted while keeping the vector sorted.
+    /// If the vector is not sorted, the returned result is unspecified and meaningless.
+    fn lower_bound(&self, value: &T) -> usize
+    where
+        T: Ord,
+    {
+        self.partition_point(|x| x < value)
+    }
+
+    /// Returns the index of the first element which is greater than the `value` in this sorted vector;
+    /// `len` if no element is greater than the `value`.
+    ///
+    /// Unlike `binary_search` which returns an arbitrary match among equal elements,
+    /// this is the deterministic last position at which the `value` can be inserted while keeping the vector sorted;
+    /// i.e., inserting at this position keeps equal elements in insertion order.
+    /// If the vector is not sorted, the returned result is unspecified and meaningless.
+    fn upper_bound(&self, value: &T) -> usize
+    where
+        T: Ord,
+    {
+        self.partition_point(|x| x <= value)
+    }
+
     /// Sorts the vector.
     ///
     /// This sort is stable.
@@ -455,6 +1869,61 @@ pub trait PinnedVec<T>:
         K: Ord;
 }
 
+/// Returns a mutable reference to the `n`-th element of the sequence formed by chaining the mutable `slices`.
+fn nth_in_slices_mut<'a, T>(
+    slices: impl Iterator<Item = &'a mut [T]>,
+    mut n: usize,
+) -> Option<&'a mut T> {
+    for slice in slices {
+        match n < slice.len() {
+            true => return slice.get_mut(n),
+            false => n -= slice.len(),
+        }
+    }
+    None
+}
+
+/// Rotates the elements within positions `begin..end` of the vector `k` positions to the right using swaps.
+fn rotate_right<T, P>(vec: &mut P, begin: usize, end: usize, k: usize)
+where
+    P: PinnedVec<T> + ?Sized,
+{
+    let len = end - begin;
+    if len > 1 {
+        let mid = end - k % len;
+        reverse(vec, begin, mid);
+        reverse(vec, mid, end);
+        reverse(vec, begin, end);
+    }
+}
+
+/// Reverses the order of the elements within positions `begin..end` of the vector using swaps.
+fn reverse<T, P>(vec: &mut P, mut begin: usize, mut end: usize)
+where
+    P: PinnedVec<T> + ?Sized,
+{
+    while begin + 1 < end {
+        vec.swap(begin, end - 1);
+        begin += 1;
+        end -= 1;
+    }
+}
+
+/// Converts the slices into an array if they are formed by exactly one slice of length `N`, ignoring empty slices.
+fn single_slice_as_array<'a, T, const N: usize>(
+    slices: impl IntoIterator<Item = &'a [T]>,
+) -> Option<&'a [T; N]>
+where
+    T: 'a,
+{
+    let mut slices = slices.into_iter().filter(|s| !s.is_empty());
+    match (slices.next(), slices.next()) {
+        (Some(slice), None) => slice.try_into().ok(),
+        (None, _) => (&[][..]).try_into().ok(),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{pinned_vec_tests::testvec::TestVec, PinnedVec};
@@ -0,0 +1,189 @@
+use crate::{
+    pinned_vec_tests::{
+        helpers::vecs::{frag_vec, test_vec},
+        testvec::TestVec,
+    },
+    PinnedVec,
+};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+#[test]
+fn to_vec_range() {
+    let vec = test_vec(10, 10);
+    let expected: Vec<_> = (0..10).collect();
+
+    assert_eq!(vec.to_vec_range(2..7), &expected[2..7]);
+    assert_eq!(vec.to_vec_range(..4), &expected[..4]);
+    assert_eq!(vec.to_vec_range(6..=9), &expected[6..=9]);
+    assert_eq!(vec.to_vec_range(..), expected);
+
+    assert!(vec.to_vec_range(3..3).is_empty());
+    assert!(vec.to_vec_range(0..0).is_empty());
+
+    assert_eq!(vec.to_vec_range(7..42), &expected[7..]);
+    assert!(vec.to_vec_range(10..20).is_empty());
+    assert!(vec.to_vec_range(42..).is_empty());
+}
+
+#[test]
+fn to_vec() {
+    let mut vec = TestVec::new(10);
+    assert!(vec.to_vec().is_empty());
+
+    for i in 0..10 {
+        vec.push(i);
+    }
+    assert_eq!(vec.to_vec(), (0..10).collect::<Vec<_>>());
+}
+
+#[test]
+fn clone_into_std_vec() {
+    let mut vec = TestVec::<u32>::new(1000);
+    assert!(vec.clone_into_std_vec().is_empty());
+
+    for i in 0..1000 {
+        vec.push(i);
+    }
+    let std_vec = vec.clone_into_std_vec();
+    assert_eq!(std_vec, (0..1000).collect::<Vec<_>>());
+    assert_eq!(std_vec, vec.to_vec());
+}
+
+#[test]
+fn get2_mut() {
+    let mut vec = test_vec(10, 10);
+
+    let (a, b) = vec.get2_mut(2, 7).expect("is-some");
+    core::mem::swap(a, b);
+    *a += 100;
+    *b += 200;
+    assert_eq!(vec.to_vec(), &[0, 1, 107, 3, 4, 5, 6, 202, 8, 9]);
+
+    let (a, b) = vec.get2_mut(9, 0).expect("is-some");
+    assert_eq!((*a, *b), (9, 0));
+}
+
+#[test]
+fn get2_mut_fragmented() {
+    let mut vec = frag_vec(20);
+
+    for (a, b) in [(3, 17), (17, 3), (7, 8), (8, 15), (19, 0)] {
+        let (x, y) = vec.get2_mut(a, b).expect("is-some");
+        assert_eq!((*x, *y), (a, b));
+        core::mem::swap(x, y);
+        vec.swap(a, b);
+    }
+    assert_eq!(vec.to_vec(), (0..20).collect::<Vec<_>>());
+    assert!(vec.get2_mut(8, 8).is_none());
+    assert!(vec.get2_mut(8, 20).is_none());
+}
+
+#[test]
+fn get2_mut_none() {
+    let mut vec = test_vec(10, 5);
+
+    assert!(vec.get2_mut(3, 3).is_none());
+    assert!(vec.get2_mut(0, 5).is_none());
+    assert!(vec.get2_mut(7, 1).is_none());
+    assert!(vec.get2_mut(12, 1).is_none());
+}
+
+#[test]
+fn get_many() {
+    let mut vec = TestVec::new(10);
+    for i in 0..10 {
+        vec.push(i.to_string());
+    }
+
+    let [a, b, c] = vec.get_many([7, 0, 3]).expect("is-some");
+    assert_eq!([a.as_str(), b.as_str(), c.as_str()], ["7", "0", "3"]);
+    assert_eq!(a as *const String, vec.get_ptr(7).expect("is-some"));
+
+    let [a, b] = vec.get_many([4, 4]).expect("is-some");
+    assert_eq!(a as *const String, b as *const String);
+    assert_eq!(a, "4");
+
+    assert_eq!(vec.get_many([]), Some([]));
+    assert!(vec.get_many([1, 10]).is_none());
+    assert!(vec.get_many([42]).is_none());
+}
+
+#[test]
+fn replace() {
+    let mut vec = test_vec(10, 10);
+    let addresses: Vec<_> = (0..10).map(|i| vec.get_ptr(i).expect("is-some")).collect();
+
+    let old = vec.replace(5, 42);
+    assert_eq!(old, 5);
+    assert_eq!(vec.get(5), Some(&42));
+    assert_eq!(vec.to_vec(), &[0, 1, 2, 3, 4, 42, 6, 7, 8, 9]);
+    for (i, addr) in addresses.into_iter().enumerate() {
+        assert_eq!(vec.get_ptr(i), Some(addr));
+    }
+}
+
+#[test]
+#[should_panic]
+fn replace_out_of_bounds() {
+    let mut vec = TestVec::new(10);
+    vec.push(0);
+    let _ = vec.replace(1, 42);
+}
+
+#[test]
+fn get_or_extend_with() {
+    let mut vec = TestVec::new(100);
+    let x = vec.get_or_extend_with(50, || 7);
+    assert_eq!(*x, 7);
+    *x = 42;
+    assert_eq!(vec.len(), 51);
+    assert!(vec.iter().take(50).all(|x| *x == 7));
+    assert_eq!(vec[50], 42);
+    let addr = vec.get_ptr(0).expect("is-some");
+
+    let mut num_calls = 0;
+    let x = vec.get_or_extend_with(3, || {
+        num_calls += 1;
+        0
+    });
+    assert_eq!(*x, 7);
+    assert_eq!(num_calls, 0);
+    assert_eq!(vec.len(), 51);
+
+    assert_eq!(*vec.get_or_extend_with(60, || 1), 1);
+    assert_eq!(vec.len(), 61);
+    assert_eq!(vec.get_ptr(0), Some(addr));
+}
+
+#[test]
+#[should_panic]
+fn get_or_extend_with_beyond_capacity() {
+    let mut vec = TestVec::new(10);
+    let _ = vec.get_or_extend_with(10, || 0);
+}
+
+#[test]
+fn first_n_last_n() {
+    let mut vec = test_vec(10, 10);
+
+    let first: Vec<_> = vec.first_n(3).into_iter().flatten().copied().collect();
+    assert_eq!(first, [0, 1, 2]);
+    let last: Vec<_> = vec.last_n(3).into_iter().flatten().copied().collect();
+    assert_eq!(last, [7, 8, 9]);
+
+    for n in [10, 20] {
+        let first: Vec<_> = vec.first_n(n).into_iter().flatten().copied().collect();
+        assert_eq!(first, vec.to_vec());
+        let last: Vec<_> = vec.last_n(n).into_iter().flatten().copied().collect();
+        assert_eq!(last, vec.to_vec());
+    }
+
+    assert_eq!(vec.first_n(0).into_iter().flatten().count(), 0);
+    assert_eq!(vec.last_n(0).into_iter().flatten().count(), 0);
+
+    vec.clear();
+    assert_eq!(vec.last_n(3).into_iter().flatten().count(), 0);
+}
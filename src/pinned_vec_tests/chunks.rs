@@ -0,0 +1,168 @@
+use crate::{
+    pinned_vec_tests::{helpers::vecs::frag_vec, testvec::TestVec},
+    PinnedVec,
+};
+use alloc::vec::Vec;
+
+#[test]
+fn chunks_exact() {
+    let mut vec = TestVec::new(100);
+    let (chunks, remainders) = vec.chunks_exact(32);
+    assert_eq!(chunks.count(), 0);
+    assert_eq!(remainders.count(), 0);
+
+    for i in 0..100 {
+        vec.push(i);
+    }
+
+    let (chunks, remainders) = vec.chunks_exact(32);
+    let chunks: Vec<_> = chunks.collect();
+    assert_eq!(chunks.len(), 3);
+    for (i, chunk) in chunks.iter().enumerate() {
+        assert_eq!(*chunk, &(i * 32..(i + 1) * 32).collect::<Vec<_>>()[..]);
+    }
+    let remainders: Vec<_> = remainders.collect();
+    assert_eq!(remainders, [&[96, 97, 98, 99][..]]);
+
+    let (chunks, remainders) = vec.chunks_exact(25);
+    assert_eq!(chunks.count(), 4);
+    assert_eq!(remainders.count(), 0);
+}
+
+#[test]
+fn chunks_exact_fragmented() {
+    let vec = frag_vec(20);
+    let (chunks, remainders) = vec.chunks_exact(3);
+    let chunks: Vec<_> = chunks.collect();
+    let remainders: Vec<_> = remainders.collect();
+
+    let expected: [&[usize]; 5] = [
+        &[0, 1, 2],
+        &[3, 4, 5],
+        &[8, 9, 10],
+        &[11, 12, 13],
+        &[16, 17, 18],
+    ];
+    assert_eq!(chunks, expected);
+    let expected: [&[usize]; 3] = [&[6, 7], &[14, 15], &[19]];
+    assert_eq!(remainders, expected);
+
+    let (chunks, remainders) = vec.chunks_exact(4);
+    assert_eq!(chunks.count(), 5);
+    assert_eq!(remainders.count(), 0);
+}
+
+#[test]
+#[should_panic]
+fn chunks_exact_zero_chunk_size() {
+    let mut vec = TestVec::new(20);
+    vec.push(0);
+    let _ = vec.chunks_exact(0);
+}
+
+#[test]
+fn rchunks() {
+    let mut vec = TestVec::new(20);
+    assert_eq!(vec.rchunks(3).count(), 0);
+
+    for i in 0..10 {
+        vec.push(i);
+    }
+    let values = vec.to_vec();
+
+    for chunk_size in [1, 3, 5, 10, 11] {
+        let chunks: Vec<_> = vec.rchunks(chunk_size).collect();
+        let expected: Vec<_> = values.rchunks(chunk_size).collect();
+        assert_eq!(chunks, expected);
+    }
+
+    let chunks: Vec<_> = vec.rchunks(3).collect();
+    assert_eq!(chunks, [&[7, 8, 9][..], &[4, 5, 6], &[1, 2, 3], &[0]]);
+}
+
+#[test]
+fn rchunks_fragmented() {
+    let vec = frag_vec(20);
+    let chunks: Vec<_> = vec.rchunks(3).collect();
+    let expected: [&[usize]; 8] = [
+        &[17, 18, 19],
+        &[16],
+        &[13, 14, 15],
+        &[10, 11, 12],
+        &[8, 9],
+        &[5, 6, 7],
+        &[2, 3, 4],
+        &[0, 1],
+    ];
+    assert_eq!(chunks, expected);
+
+    let values: Vec<_> = vec.rchunks(8).map(|c| c[0]).collect();
+    assert_eq!(values, [16, 8, 0]);
+}
+
+#[test]
+#[should_panic]
+fn rchunks_zero_chunk_size() {
+    let mut vec = TestVec::new(20);
+    vec.push(0);
+    let _ = vec.rchunks(0);
+}
+
+#[test]
+fn first_chunk() {
+    let mut vec = TestVec::new(10);
+    assert_eq!(vec.first_chunk::<0>(), Some(&[]));
+    assert_eq!(vec.first_chunk::<1>(), None);
+
+    vec.push(0);
+    vec.push(1);
+    assert_eq!(vec.first_chunk::<3>(), None);
+
+    for i in 2..10 {
+        vec.push(i);
+    }
+    assert_eq!(vec.first_chunk::<3>(), Some(&[0, 1, 2]));
+    assert_eq!(
+        vec.first_chunk::<10>(),
+        Some(&[0, 1, 2, 3, 4, 5, 6, 7, 8, 9])
+    );
+    assert_eq!(vec.first_chunk::<11>(), None);
+}
+
+#[test]
+fn last_chunk() {
+    let mut vec = TestVec::new(10);
+    assert_eq!(vec.last_chunk::<0>(), Some(&[]));
+    assert_eq!(vec.last_chunk::<1>(), None);
+
+    vec.push(0);
+    vec.push(1);
+    assert_eq!(vec.last_chunk::<3>(), None);
+
+    for i in 2..10 {
+        vec.push(i);
+    }
+    assert_eq!(vec.last_chunk::<3>(), Some(&[7, 8, 9]));
+    assert_eq!(
+        vec.last_chunk::<10>(),
+        Some(&[0, 1, 2, 3, 4, 5, 6, 7, 8, 9])
+    );
+    assert_eq!(vec.last_chunk::<11>(), None);
+}
+
+#[test]
+fn first_chunk_last_chunk_fragmented() {
+    let vec = frag_vec(20);
+
+    assert_eq!(vec.first_chunk::<8>(), Some(&[0, 1, 2, 3, 4, 5, 6, 7]));
+    assert_eq!(vec.first_chunk::<3>(), Some(&[0, 1, 2]));
+    assert_eq!(vec.first_chunk::<9>(), None);
+
+    assert_eq!(vec.last_chunk::<4>(), Some(&[16, 17, 18, 19]));
+    assert_eq!(vec.last_chunk::<5>(), None);
+    assert_eq!(vec.last_chunk::<20>(), None);
+
+    let vec = frag_vec(16);
+    assert_eq!(vec.last_chunk::<8>(), Some(&[8, 9, 10, 11, 12, 13, 14, 15]));
+    assert_eq!(vec.last_chunk::<9>(), None);
+}
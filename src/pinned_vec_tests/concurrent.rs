@@ -0,0 +1,173 @@
+use crate::{ConcurrentPinnedVec, PinnedVec};
+
+const NUM_THREADS: usize = 8;
+const MAX_LEN_PER_THREAD: usize = 1024;
+const NUM_GROWTH_STEPS: usize = 16;
+
+/// Stress tests concurrent growth of the concurrent pinned vector created by `make`;
+/// panics if the implementation `C` fails to keep the written values while growing concurrently.
+///
+/// Growth happens in steps towards the `max_capacity` of the vector.
+/// At each step, all threads race to `grow_to` the same target capacity,
+/// and then write with `write_at` to interleaved positions of the newly grown range, thread `t` writing to positions `i` with `i % NUM_THREADS == t`.
+/// Meanwhile, each thread asserts that the values it has written in earlier steps are kept.
+/// Finally, the vector is converted back with `into_inner` and every position is asserted to hold its expected value.
+///
+/// # Panics
+///
+/// Panics if the concurrent pinned vector implementation `C` fails to grow up to its `max_capacity`,
+/// or if any of the written values is lost or changed during concurrent growth.
+pub fn test_concurrent_grow<C, F>(make: F)
+where
+    C: ConcurrentPinnedVec<usize> + Sync,
+    F: Fn() -> C,
+{
+    let vec = make();
+
+    let total = vec.max_capacity().min(NUM_THREADS * MAX_LEN_PER_THREAD);
+    let target = |step: usize| ((step + 1) * total).div_ceil(NUM_GROWTH_STEPS);
+
+    std::thread::scope(|s| {
+        for t in 0..NUM_THREADS {
+            let vec = &vec;
+            s.spawn(move || {
+                let mut begin = 0;
+                for step in 0..NUM_GROWTH_STEPS {
+                    let end = target(step);
+                    let capacity = vec.grow_to(end).expect("must grow within max_capacity");
+                    assert!(capacity >= end);
+
+                    for i in (begin..end).filter(|i| i % NUM_THREADS == t) {
+                        unsafe { vec.write_at(i, i) };
+                    }
+                    for i in (0..end).filter(|i| i % NUM_THREADS == t) {
+                        assert_eq!(unsafe { vec.get(i) }, Some(&i));
+                    }
+                    begin = end;
+                }
+            });
+        }
+    });
+
+    assert!(vec.capacity() >= total);
+    let vec = unsafe { vec.into_inner(total) };
+    assert_eq!(vec.len(), total);
+    for i in 0..total {
+        assert_eq!(vec.get(i), Some(&i));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        pinned_vec_tests::testvec::{ConTestVec, FragVec, TestVec},
+        IntoConcurrentPinnedVec,
+    };
+    use alloc::vec::Vec;
+    use core::ops::RangeBounds;
+
+    const CAPACITY: usize = 20;
+
+    fn filled_con_vec() -> ConTestVec<usize> {
+        let vec = TestVec::new(CAPACITY).into_concurrent();
+        for i in 0..CAPACITY {
+            unsafe { vec.write_at(i, i) };
+        }
+        vec
+    }
+
+    fn slices_mut_values<R: RangeBounds<usize>>(vec: &ConTestVec<usize>, range: R) -> Vec<usize> {
+        let slices = unsafe { vec.slices_mut(range) };
+        slices.into_iter().flat_map(|s| s.iter().copied()).collect()
+    }
+
+    #[test]
+    fn test_concurrent_grow_empty() {
+        test_concurrent_grow(|| TestVec::new(0).into_concurrent());
+    }
+
+    #[test]
+    fn test_concurrent_grow_small() {
+        test_concurrent_grow(|| TestVec::new(33).into_concurrent());
+    }
+
+    #[test]
+    fn test_concurrent_grow_medium() {
+        test_concurrent_grow(|| TestVec::new(NUM_THREADS * MAX_LEN_PER_THREAD).into_concurrent());
+    }
+
+    #[test]
+    fn test_concurrent_grow_allocating() {
+        test_concurrent_grow(|| {
+            let vec = FragVec::new().into_concurrent();
+            assert_eq!(vec.capacity(), 0);
+            vec
+        });
+    }
+
+    #[test]
+    fn test_concurrent_grow_allocating_non_empty() {
+        test_concurrent_grow(|| {
+            let mut vec = FragVec::new();
+            vec.reserve(20);
+            vec.into_concurrent()
+        });
+    }
+
+    #[test]
+    fn slices_mut_within_capacity() {
+        let vec = filled_con_vec();
+        assert_eq!(slices_mut_values(&vec, 3..8), (3..8).collect::<Vec<_>>());
+        assert_eq!(slices_mut_values(&vec, 3..=8), (3..9).collect::<Vec<_>>());
+        assert_eq!(slices_mut_values(&vec, ..5), (0..5).collect::<Vec<_>>());
+        assert_eq!(
+            slices_mut_values(&vec, ..),
+            (0..CAPACITY).collect::<Vec<_>>()
+        );
+
+        let slices = unsafe { vec.slices_mut(4..6) };
+        for x in slices.into_iter().flat_map(|s| s.iter_mut()) {
+            *x += 100;
+        }
+        let vec = unsafe { vec.into_inner(CAPACITY) };
+        for i in 0..CAPACITY {
+            let expected = if (4..6).contains(&i) { i + 100 } else { i };
+            assert_eq!(vec.get(i), Some(&expected));
+        }
+    }
+
+    #[test]
+    fn slices_mut_empty_range() {
+        let vec = filled_con_vec();
+        for i in [0, 7, CAPACITY] {
+            assert!(slices_mut_values(&vec, i..i).is_empty());
+        }
+        let _ = unsafe { vec.into_inner(CAPACITY) };
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn slices_mut_inverted_range() {
+        let vec = filled_con_vec();
+        assert!(slices_mut_values(&vec, 8..3).is_empty());
+        assert!(slices_mut_values(&vec, (CAPACITY + 5)..3).is_empty());
+        let _ = unsafe { vec.into_inner(CAPACITY) };
+    }
+
+    #[test]
+    fn slices_mut_beyond_capacity() {
+        let vec = filled_con_vec();
+        assert_eq!(
+            slices_mut_values(&vec, 15..(CAPACITY + 10)),
+            (15..CAPACITY).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            slices_mut_values(&vec, ..=(CAPACITY + 10)),
+            (0..CAPACITY).collect::<Vec<_>>()
+        );
+        assert!(slices_mut_values(&vec, CAPACITY..(CAPACITY + 10)).is_empty());
+        assert!(slices_mut_values(&vec, (CAPACITY + 3)..(CAPACITY + 10)).is_empty());
+        let _ = unsafe { vec.into_inner(CAPACITY) };
+    }
+}
@@ -0,0 +1,120 @@
+use super::refmap::RefMap;
+use crate::PinnedVec;
+
+/// Tests the pinned vector guarantee on growing the vector and initializing the new positions;
+/// panics if the pinned vector implementation `P` does not satisfy the required condition.
+///
+/// Tested pinned element guarantee:
+///
+/// * **G1: pinned elements on growth at the end**. Growing the capacity of the vector with **grow_and_initialize** extends the vector to its new capacity by adding elements to the end of the vector. Pinned vector guarantees that the memory locations of the already existing elements will not change due to this mutation.
+///
+/// # Panics
+///
+/// Panics if the pinned vector implementation `P` does not satisfy the abovementioned pinned elements guarantee.
+pub fn grow<P: PinnedVec<usize>>(pinned_vec: P, max_allowed_test_len: usize) -> P {
+    let mut vec = pinned_vec;
+    vec.clear();
+
+    let first_quarter = max_allowed_test_len / 4;
+    let half = max_allowed_test_len / 2;
+
+    let mut refmap = RefMap::new(200, max_allowed_test_len);
+
+    for i in 0..first_quarter {
+        vec.push(i);
+        refmap.set_reference(&vec, i);
+    }
+    refmap.validate_references(&vec);
+
+    let mut next = first_quarter;
+    let fill = || {
+        next += 1;
+        next - 1
+    };
+    match vec.grow_and_initialize(half, fill) {
+        Ok(capacity) => {
+            assert!(capacity >= half);
+            assert_eq!(vec.len(), capacity);
+            assert_eq!(vec.capacity(), capacity);
+            for i in first_quarter..vec.len() {
+                assert_eq!(vec.get(i), Some(&i));
+                refmap.set_reference(&vec, i);
+            }
+        }
+        Err(_) => assert!(vec.capacity() < half),
+    }
+    refmap.validate_references(&vec);
+
+    // push beyond the grown region and grow again
+
+    vec.truncate(first_quarter);
+    for i in first_quarter..max_allowed_test_len {
+        refmap.drop_reference(i);
+    }
+    refmap.validate_references(&vec);
+
+    for i in first_quarter..max_allowed_test_len {
+        vec.push(i);
+        refmap.set_reference(&vec, i);
+    }
+    refmap.validate_references(&vec);
+
+    let mut next = max_allowed_test_len;
+    let fill = || {
+        next += 1;
+        next - 1
+    };
+    let capacity = vec
+        .grow_and_initialize(max_allowed_test_len, fill)
+        .expect("must be able to grow up to max_allowed_test_len");
+    assert!(capacity >= max_allowed_test_len);
+    assert_eq!(vec.len(), capacity);
+    for i in 0..vec.len() {
+        assert_eq!(vec.get(i), Some(&i));
+    }
+    refmap.validate_references(&vec);
+
+    vec.truncate(max_allowed_test_len);
+
+    vec
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pinned_vec_tests::testvec::TestVec;
+
+    #[test]
+    fn test_grow_empty() {
+        let pinned_vec = TestVec::new(0);
+        grow(pinned_vec, 0);
+    }
+
+    #[test]
+    fn test_grow_small() {
+        let capacity = 40;
+        let pinned_vec = TestVec::new(capacity);
+        grow(pinned_vec, capacity);
+    }
+
+    #[test]
+    fn test_grow_medium() {
+        let capacity = 512;
+        let pinned_vec = TestVec::new(capacity);
+        grow(pinned_vec, capacity);
+    }
+
+    #[test]
+    fn test_grow_large() {
+        let capacity = 4 * 1024;
+        let pinned_vec = TestVec::new(capacity);
+        grow(pinned_vec, capacity);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_grow_beyond_fixed_capacity() {
+        let pinned_vec = TestVec::new(40);
+        grow(pinned_vec, 80);
+    }
+}
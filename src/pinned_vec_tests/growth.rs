@@ -0,0 +1,182 @@
+use crate::{
+    pinned_vec_tests::{
+        helpers::vecs::{frag_vec, test_vec},
+        refmap::RefMap,
+        testvec::TestVec,
+    },
+    PinnedVec, PinnedVecGrowthError,
+};
+use alloc::{string::String, vec::Vec};
+use orx_pseudo_default::PseudoDefault;
+
+#[test]
+fn extend_from_slice_reserved() {
+    let values: Vec<_> = (0..100).collect();
+
+    let mut vec = TestVec::pseudo_default();
+    vec.extend_from_slice_reserved(&values);
+    assert_eq!(vec.num_reserve_calls(), 1);
+    assert_eq!(vec.to_vec(), values);
+
+    let mut vec = TestVec::new(200);
+    vec.extend_from_slice_reserved(&values[..50]);
+    vec.extend_from_slice_reserved(&values[50..]);
+    assert_eq!(vec.num_reserve_calls(), 2);
+    assert_eq!(vec.to_vec(), values);
+}
+
+#[test]
+fn extend_from_within() {
+    let mut vec = TestVec::new(20);
+    let mut refmap = RefMap::new(200, 10);
+    for i in 0..10 {
+        vec.push(i);
+        refmap.set_reference(&vec, i);
+    }
+
+    vec.extend_from_within(2..5);
+    assert_eq!(vec.to_vec(), &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 2, 3, 4]);
+    refmap.validate_references(&vec);
+
+    vec.extend_from_within(11..);
+    assert_eq!(vec.to_vec(), &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 2, 3, 4, 3, 4]);
+
+    vec.extend_from_within(20..30);
+    assert_eq!(vec.len(), 15);
+    refmap.validate_references(&vec);
+}
+
+#[test]
+fn resize() {
+    let mut vec = test_vec(20, 5);
+    let addresses: Vec<_> = (0..5).map(|i| vec.get_ptr(i).expect("is-some")).collect();
+
+    vec.resize(8, 42);
+    assert_eq!(vec.len(), 8);
+    assert_eq!(vec.to_vec(), &[0, 1, 2, 3, 4, 42, 42, 42]);
+    for (i, addr) in addresses.iter().enumerate() {
+        assert_eq!(vec.get_ptr(i), Some(*addr));
+    }
+
+    vec.resize(8, 7);
+    assert_eq!(vec.len(), 8);
+    assert_eq!(vec.to_vec(), &[0, 1, 2, 3, 4, 42, 42, 42]);
+
+    vec.resize(3, 7);
+    assert_eq!(vec.len(), 3);
+    assert_eq!(vec.to_vec(), &[0, 1, 2]);
+    for (i, addr) in addresses.iter().take(3).enumerate() {
+        assert_eq!(vec.get_ptr(i), Some(*addr));
+    }
+
+    vec.resize(20, 1);
+    assert_eq!(vec.len(), 20);
+    assert_eq!(vec.capacity(), 20);
+}
+
+#[test]
+#[should_panic]
+fn resize_beyond_capacity() {
+    let mut vec = TestVec::new(20);
+    vec.resize(21, 0);
+}
+
+#[test]
+fn grow_and_initialize() {
+    let mut vec = TestVec::<usize>::new(10);
+    vec.push(0);
+    vec.push(1);
+    let first = vec.get(0).expect("is-some") as *const usize;
+
+    let mut next = 2;
+    let mut fill = || {
+        next += 1;
+        next - 1
+    };
+    assert_eq!(vec.grow_and_initialize(5, &mut fill), Ok(10));
+    assert_eq!(vec.len(), 10);
+    assert_eq!(vec.to_vec(), (0..10).collect::<Vec<_>>());
+    assert_eq!(vec.get(0).expect("is-some") as *const usize, first);
+
+    assert_eq!(vec.grow_and_initialize(10, &mut fill), Ok(10));
+    assert_eq!(vec.len(), 10);
+
+    assert_eq!(
+        vec.grow_and_initialize(11, &mut fill),
+        Err(PinnedVecGrowthError::FailedToGrowWhileKeepingElementsPinned)
+    );
+    assert_eq!(vec.to_vec(), (0..10).collect::<Vec<_>>());
+}
+
+#[test]
+fn capacity_bound() {
+    let mut vec = TestVec::<usize>::new(42);
+    assert_eq!(vec.capacity_bound(), 42);
+
+    vec.push(1);
+    assert_eq!(vec.capacity_bound(), 42);
+}
+
+#[test]
+fn reserve_concurrent() {
+    let mut vec = TestVec::<usize>::new(0);
+    let capacity = vec.reserve_concurrent(1000);
+    assert!(capacity >= vec.len() + 1000);
+    assert_eq!(capacity, vec.capacity_state().maximum_concurrent_capacity());
+    assert_eq!(vec.num_reserve_calls(), 1);
+
+    let mut vec = test_vec(2000, 10);
+    let capacity = vec.reserve_concurrent(1000);
+    assert!(capacity >= vec.len() + 1000);
+    assert_eq!(vec.num_reserve_calls(), 1);
+}
+
+#[test]
+fn spare_capacity_mut() {
+    let mut vec = TestVec::new(10);
+    vec.push(0);
+    vec.push(1);
+    assert_eq!(vec.spare_capacity_mut().count(), 8);
+
+    for (i, slot) in vec.spare_capacity_mut().take(5).enumerate() {
+        slot.write(10 + i);
+    }
+    unsafe { vec.set_len(7) };
+    assert_eq!(vec.to_vec(), &[0, 1, 10, 11, 12, 13, 14]);
+    assert_eq!(vec.spare_capacity_mut().count(), 3);
+
+    let mut vec = TestVec::new(3);
+    vec.push(String::from("a"));
+    for slot in vec.spare_capacity_mut() {
+        slot.write(String::from("b"));
+    }
+    unsafe { vec.set_len(3) };
+    assert_eq!(vec.to_vec(), &["a", "b", "b"]);
+    assert_eq!(vec.spare_capacity_mut().count(), 0);
+}
+
+#[test]
+fn spare_capacity_mut_fragmented() {
+    let mut vec = frag_vec(10);
+    vec.reserve(8);
+    assert_eq!(vec.spare_capacity_mut().count(), 14);
+
+    for (i, slot) in vec.spare_capacity_mut().take(10).enumerate() {
+        slot.write(10 + i);
+    }
+    unsafe { vec.set_len(20) };
+    assert_eq!(vec.to_vec(), (0..20).collect::<Vec<_>>());
+    assert_eq!(vec.spare_capacity_mut().count(), 4);
+}
+
+#[test]
+fn allocated_bytes() {
+    let mut vec = TestVec::<u64>::new(100);
+    assert_eq!(vec.allocated_bytes(), 800);
+
+    vec.push(42);
+    assert_eq!(vec.allocated_bytes(), 800);
+
+    let vec = TestVec::<()>::new(100);
+    assert_eq!(vec.allocated_bytes(), 0);
+}
@@ -0,0 +1,22 @@
+use crate::{
+    pinned_vec_tests::testvec::{FragVec, TestVec},
+    PinnedVec,
+};
+
+/// Creates a vector with the given `capacity` and elements `0..len`.
+pub fn test_vec(capacity: usize, len: usize) -> TestVec<usize> {
+    let mut vec = TestVec::new(capacity);
+    for i in 0..len {
+        vec.push(i);
+    }
+    vec
+}
+
+/// Creates a fragmented vector with elements `0..len` stored in fragments of 8 elements.
+pub fn frag_vec(len: usize) -> FragVec<usize> {
+    let mut vec = FragVec::new();
+    for i in 0..len {
+        vec.push(i);
+    }
+    vec
+}
@@ -0,0 +1,89 @@
+use crate::{
+    pinned_vec_tests::{helpers::vecs::test_vec, testvec::TestVec},
+    PinnedVec,
+};
+use alloc::vec::Vec;
+
+#[test]
+fn insert_from_slice() {
+    let mut vec = test_vec(20, 10);
+    let addresses: Vec<_> = (0..3).map(|i| vec.get_ptr(i).expect("is-some")).collect();
+
+    vec.insert_from_slice(3, &[100, 101]);
+    assert_eq!(vec.to_vec(), &[0, 1, 2, 100, 101, 3, 4, 5, 6, 7, 8, 9]);
+    for (i, addr) in addresses.into_iter().enumerate() {
+        assert_eq!(vec.get_ptr(i), Some(addr));
+    }
+
+    vec.insert_from_slice(0, &[42]);
+    assert_eq!(vec.to_vec(), &[42, 0, 1, 2, 100, 101, 3, 4, 5, 6, 7, 8, 9]);
+
+    let len = vec.len();
+    vec.insert_from_slice(len, &[7, 7]);
+    assert_eq!(
+        vec.to_vec(),
+        &[42, 0, 1, 2, 100, 101, 3, 4, 5, 6, 7, 8, 9, 7, 7]
+    );
+
+    vec.insert_from_slice(5, &[]);
+    assert_eq!(vec.len(), 15);
+}
+
+#[test]
+#[should_panic]
+fn insert_from_slice_out_of_bounds() {
+    let mut vec = TestVec::new(20);
+    vec.push(0);
+    vec.insert_from_slice(2, &[1]);
+}
+
+#[test]
+fn binary_insert() {
+    let mut vec = TestVec::new(20);
+    let mut expected = Vec::new();
+    for i in 0..20 {
+        let value = (i * 7 + 3) % 20;
+        let index = vec.binary_insert(value);
+        assert_eq!(vec.get(index), Some(&value));
+
+        expected.push(value);
+        expected.sort();
+        assert_eq!(vec.to_vec(), expected);
+    }
+    assert!(vec.is_sorted());
+    assert_eq!(vec.to_vec(), (0..20).collect::<Vec<_>>());
+}
+
+#[test]
+fn binary_insert_duplicates() {
+    let mut vec = TestVec::new(20);
+    for x in [3, 1, 3, 2, 3, 1] {
+        let index = vec.binary_insert(x);
+        assert_eq!(vec.get(index), Some(&x));
+    }
+    assert_eq!(vec.to_vec(), &[1, 1, 2, 3, 3, 3]);
+}
+
+#[test]
+fn move_to_front() {
+    let mut vec = test_vec(10, 5);
+    let last = vec.get_ptr(4).expect("is-some");
+
+    vec.move_to_front(3);
+    assert_eq!(vec.to_vec(), &[3, 0, 1, 2, 4]);
+    assert_eq!(vec.get_ptr(4), Some(last));
+
+    vec.move_to_front(0);
+    assert_eq!(vec.to_vec(), &[3, 0, 1, 2, 4]);
+
+    vec.move_to_front(4);
+    assert_eq!(vec.to_vec(), &[4, 3, 0, 1, 2]);
+}
+
+#[test]
+#[should_panic]
+fn move_to_front_out_of_bounds() {
+    let mut vec = TestVec::new(10);
+    vec.push(0);
+    vec.move_to_front(1);
+}
@@ -0,0 +1,71 @@
+use super::refmap::RefMap;
+use crate::PinnedVec;
+use alloc::vec::Vec;
+
+pub fn iter_rev<P: PinnedVec<usize>>(pinned_vec: P, max_allowed_test_len: usize) -> P {
+    let mut vec = pinned_vec;
+    vec.clear();
+
+    let mut refmap = RefMap::new(200, max_allowed_test_len);
+
+    for i in 0..max_allowed_test_len {
+        vec.push(i);
+        refmap.set_reference(&vec, i);
+    }
+
+    let values: Vec<_> = vec.iter_rev().copied().collect();
+    let expected: Vec<_> = (0..max_allowed_test_len).rev().collect();
+    assert_eq!(values, expected);
+
+    for (i, x) in vec.iter_rev().enumerate() {
+        let idx = max_allowed_test_len - 1 - i;
+        assert_eq!(
+            x as *const usize,
+            vec.get(idx).expect("is-some") as *const usize
+        );
+    }
+    refmap.validate_references(&vec);
+
+    for (i, x) in vec.iter_mut_rev().enumerate() {
+        *x = 10 * i;
+    }
+    for i in 0..max_allowed_test_len {
+        assert_eq!(vec.get(i), Some(&(10 * (max_allowed_test_len - 1 - i))));
+    }
+
+    for (i, x) in vec.iter_mut_rev().enumerate() {
+        *x = max_allowed_test_len - 1 - i;
+    }
+    for i in 0..max_allowed_test_len {
+        assert_eq!(vec.get(i), Some(&i));
+    }
+    refmap.validate_references(&vec);
+
+    vec
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pinned_vec_tests::testvec::TestVec;
+
+    #[test]
+    fn test_iter_rev_empty() {
+        let pinned_vec = TestVec::new(0);
+        iter_rev(pinned_vec, 0);
+    }
+
+    #[test]
+    fn test_iter_rev_small() {
+        let capacity = 40;
+        let pinned_vec = TestVec::new(capacity);
+        iter_rev(pinned_vec, capacity);
+    }
+
+    #[test]
+    fn test_iter_rev_medium() {
+        let capacity = 512;
+        let pinned_vec = TestVec::new(capacity);
+        iter_rev(pinned_vec, capacity);
+    }
+}
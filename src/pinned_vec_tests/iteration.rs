@@ -0,0 +1,163 @@
+use crate::{
+    pinned_vec_tests::{
+        helpers::vecs::{frag_vec, test_vec},
+        testvec::TestVec,
+    },
+    PinnedVec,
+};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+#[test]
+fn iter_indexed() {
+    let mut vec = TestVec::new(20);
+    assert_eq!(vec.iter_indexed().count(), 0);
+
+    for i in 0..20 {
+        vec.push(i * 10);
+    }
+
+    let mut count = 0;
+    for (i, x) in vec.iter_indexed() {
+        assert_eq!(i, count);
+        assert_eq!(*x, i * 10);
+        assert_eq!(
+            x as *const usize,
+            vec.get(i).expect("is-some") as *const usize
+        );
+        count += 1;
+    }
+    assert_eq!(count, vec.len());
+}
+
+#[test]
+fn iter_mut_indexed() {
+    let mut vec = TestVec::new(20);
+    assert_eq!(vec.iter_mut_indexed().count(), 0);
+
+    for _ in 0..20 {
+        vec.push(42);
+    }
+
+    for (i, x) in vec.iter_mut_indexed() {
+        *x = i;
+    }
+    assert_eq!(vec.to_vec(), (0..20).collect::<Vec<_>>());
+}
+
+#[test]
+fn into_iter_rev() {
+    let vec = TestVec::<String>::new(10);
+    assert_eq!(vec.into_iter_rev().count(), 0);
+
+    let mut vec = TestVec::new(10);
+    for i in 0..10 {
+        vec.push(i.to_string());
+    }
+    let values: Vec<_> = vec.into_iter_rev().collect();
+    let expected: Vec<_> = (0..10).rev().map(|x| x.to_string()).collect();
+    assert_eq!(values, expected);
+}
+
+#[test]
+fn iter_while() {
+    let mut vec = TestVec::new(100);
+    assert_eq!(vec.iter_while(|_| true).count(), 0);
+
+    for i in 0..100 {
+        vec.push(i);
+    }
+    let prefix: Vec<_> = vec.iter_while(|x| *x < 30).copied().collect();
+    assert_eq!(prefix, (0..30).collect::<Vec<_>>());
+
+    assert_eq!(vec.iter_while(|x| x % 2 == 0).count(), 1);
+    assert_eq!(vec.iter_while(|_| true).count(), 100);
+    assert_eq!(vec.iter_while(|_| false).count(), 0);
+}
+
+#[test]
+fn iter_mut_take() {
+    let mut vec = TestVec::new(20);
+    assert_eq!(vec.iter_mut_take(5).count(), 0);
+
+    for i in 0..20 {
+        vec.push(i);
+    }
+
+    for x in vec.iter_mut_take(5) {
+        *x += 1;
+    }
+    let expected: Vec<_> = (1..6).chain(5..20).collect();
+    assert_eq!(vec.to_vec(), expected);
+
+    assert_eq!(vec.iter_mut_take(0).count(), 0);
+    assert_eq!(vec.iter_mut_take(42).count(), 20);
+}
+
+#[test]
+fn iter_pairs() {
+    let mut vec = TestVec::new(10);
+    assert_eq!(vec.iter_pairs().count(), 0);
+
+    vec.push(0);
+    assert_eq!(vec.iter_pairs().count(), 0);
+
+    for i in 1..10 {
+        vec.push(i);
+    }
+    let pairs: Vec<_> = vec.iter_pairs().map(|(a, b)| (*a, *b)).collect();
+    let expected: Vec<_> = (0..9).map(|i| (i, i + 1)).collect();
+    assert_eq!(pairs, expected);
+}
+
+#[test]
+fn iter_pairs_fragmented() {
+    let vec = frag_vec(20);
+    let pairs: Vec<_> = vec.iter_pairs().map(|(a, b)| (*a, *b)).collect();
+    assert_eq!(pairs, (0..19).map(|i| (i, i + 1)).collect::<Vec<_>>());
+
+    // pairs across fragment boundaries refer to elements of two different fragments
+    for i in [7, 15] {
+        let (a, b) = vec.iter_pairs().nth(i).expect("is-some");
+        assert_eq!(a as *const usize, vec.get_ptr(i).expect("is-some"));
+        assert_eq!(b as *const usize, vec.get_ptr(i + 1).expect("is-some"));
+    }
+}
+
+#[test]
+fn fold_ref() {
+    let mut vec = TestVec::new(100);
+    assert_eq!(vec.fold_ref(0, |acc, x| acc + x), 0);
+    for i in 0..100 {
+        vec.push(i);
+    }
+    assert_eq!(vec.fold_ref(0, |acc, x| acc + x), 4950);
+    assert_eq!(
+        vec.fold_ref(Vec::new(), |mut acc, x| {
+            acc.push(*x);
+            acc
+        }),
+        vec.to_vec()
+    );
+}
+
+#[test]
+fn try_fold_ref() {
+    let vec = test_vec(100, 100);
+
+    let sum: Result<usize, usize> = vec.try_fold_ref(0, |acc, x| Ok(acc + x));
+    assert_eq!(sum, Ok(4950));
+
+    let mut num_visited = 0;
+    let sum = vec.try_fold_ref(0, |acc, x| {
+        num_visited += 1;
+        match *x < 10 {
+            true => Ok(acc + x),
+            false => Err(*x),
+        }
+    });
+    assert_eq!(sum, Err(10));
+    assert_eq!(num_visited, 11);
+}
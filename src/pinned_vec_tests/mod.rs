@@ -1,24 +1,60 @@
 mod binary_search;
+/// Tests methods to validate concurrent growth guarantees of a `ConcurrentPinnedVec` implementation.
+#[cfg(any(test, feature = "std"))]
+pub mod concurrent;
 mod extend;
+mod grow_and_initialize;
 mod insert;
+mod iter_rev;
 mod pop;
 mod push;
-mod refmap;
+mod ref_validator;
+pub(crate) mod refmap;
 mod remove;
+mod set_len;
 mod slices;
-mod test_all;
+mod swap;
+pub(crate) mod test_all;
+mod test_all_generic;
 mod truncate;
 mod unsafe_writer;
 
+#[cfg(test)]
+mod access;
+#[cfg(test)]
+mod chunks;
+#[cfg(test)]
+mod growth;
 #[cfg(test)]
 mod helpers;
 #[cfg(test)]
+mod insertions;
+#[cfg(test)]
+mod iteration;
+#[cfg(test)]
+mod pointers;
+#[cfg(test)]
+mod query;
+#[cfg(test)]
+mod removals;
+#[cfg(test)]
+mod retain;
+#[cfg(test)]
+mod search;
+#[cfg(test)]
+mod slice_views;
+#[cfg(test)]
+mod swaps;
+#[cfg(test)]
 pub(crate) mod testvec;
 
 pub use extend::extend;
+pub use grow_and_initialize::grow;
 pub use insert::insert;
 pub use pop::pop;
 pub use push::push;
+pub use ref_validator::RefValidator;
 pub use remove::remove;
 pub use test_all::test_pinned_vec;
+pub use test_all_generic::test_pinned_vec_generic;
 pub use truncate::truncate;
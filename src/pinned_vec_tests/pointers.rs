@@ -0,0 +1,227 @@
+use crate::{
+    pinned_vec_tests::{helpers::vecs::test_vec, testvec::TestVec},
+    IndexOfError, PinnedVec,
+};
+use alloc::vec::Vec;
+
+#[test]
+fn require_index_of_ptr() {
+    let vec = test_vec(10, 10);
+
+    for i in 0..10 {
+        let ptr = vec.get(i).expect("is-some") as *const usize;
+        assert_eq!(vec.require_index_of_ptr(ptr), i);
+    }
+}
+
+#[test]
+#[should_panic(expected = "pointer does not belong to this pinned vec")]
+fn require_index_of_ptr_foreign() {
+    let mut vec = TestVec::new(10);
+    vec.push(0);
+
+    let foreign = 0usize;
+    vec.require_index_of_ptr(&foreign as *const usize);
+}
+
+#[test]
+fn index_lookup_ptr_at_round_trip() {
+    let mut vec = TestVec::<usize>::new(20);
+    for i in 0..15 {
+        vec.push(i);
+    }
+
+    for i in 0..vec.len() {
+        let ptr = vec.ptr_at(i).expect("is-some");
+        assert_eq!(ptr, vec.get_ptr(i).expect("is-some"));
+        assert_eq!(vec.index_lookup(ptr), Some(i));
+        assert_eq!(vec.index_lookup(ptr), vec.index_of_ptr(ptr));
+    }
+
+    for i in vec.len()..vec.capacity() {
+        let ptr = vec.ptr_at(i).expect("is-some");
+        assert_eq!(vec.index_lookup(ptr), None);
+    }
+
+    assert_eq!(vec.ptr_at(vec.capacity()), None);
+
+    let x = 42usize;
+    assert_eq!(vec.index_lookup(&x as *const usize), None);
+}
+
+#[test]
+fn get_initialized_ptr() {
+    let vec = test_vec(20, 15);
+
+    for i in 0..vec.len() {
+        let ptr = vec.get_initialized_ptr(i).expect("is-some");
+        assert_eq!(Some(ptr), vec.get_ptr(i));
+        assert_eq!(unsafe { *ptr }, i);
+    }
+
+    for i in vec.len()..vec.capacity() {
+        assert!(vec.get_initialized_ptr(i).is_none());
+        assert!(vec.get_ptr(i).is_some());
+    }
+
+    assert!(vec.get_initialized_ptr(vec.capacity()).is_none());
+    assert!(vec.get_ptr(vec.capacity()).is_none());
+}
+
+#[test]
+fn validate_pinned_invariant() {
+    let mut vec = TestVec::new(100);
+    assert_eq!(vec.validate_pinned_invariant(&[]), Ok(()));
+
+    let mut recorded = Vec::new();
+    for i in 0..50 {
+        vec.push(i);
+        if i % 7 == 0 {
+            recorded.push((i, vec.get_ptr(i).expect("is-some")));
+        }
+    }
+    assert_eq!(vec.validate_pinned_invariant(&recorded), Ok(()));
+
+    for i in 50..100 {
+        vec.push(i);
+    }
+    assert_eq!(vec.validate_pinned_invariant(&recorded), Ok(()));
+
+    vec.truncate(30);
+    assert_eq!(vec.validate_pinned_invariant(&recorded), Err(35));
+
+    recorded.retain(|(i, _)| *i < 30);
+    assert_eq!(vec.validate_pinned_invariant(&recorded), Ok(()));
+
+    vec.swap(7, 8);
+    assert_eq!(vec.validate_pinned_invariant(&recorded), Ok(()));
+
+    vec.clear();
+    assert_eq!(vec.validate_pinned_invariant(&recorded), Err(0));
+}
+
+#[test]
+fn validate_pinned_invariant_mismatched_ptr() {
+    let vec = test_vec(10, 10);
+    let ptr = vec.get_ptr(3).expect("is-some");
+    assert_eq!(vec.validate_pinned_invariant(&[(3, ptr)]), Ok(()));
+    assert_eq!(vec.validate_pinned_invariant(&[(3, ptr), (4, ptr)]), Err(4));
+}
+
+#[test]
+fn checked_index_of_ptr() {
+    let mut vec = TestVec::<u32>::new(20);
+    let ptr = vec.get_ptr(0).expect("is-some");
+    assert_eq!(vec.checked_index_of_ptr(ptr), Err(IndexOfError::Empty));
+
+    for i in 0..10 {
+        vec.push(i);
+    }
+    for i in 0..10 {
+        let ptr = vec.get_ptr(i).expect("is-some");
+        assert_eq!(vec.checked_index_of_ptr(ptr), Ok(i));
+    }
+
+    let below = vec.get_ptr(0).expect("is-some").wrapping_sub(1);
+    assert_eq!(
+        vec.checked_index_of_ptr(below),
+        Err(IndexOfError::BelowRange)
+    );
+
+    let above = vec.get_ptr(10).expect("is-some");
+    assert_eq!(
+        vec.checked_index_of_ptr(above),
+        Err(IndexOfError::AboveRange)
+    );
+    let above = vec.get_ptr(9).expect("is-some").wrapping_add(1000);
+    assert_eq!(
+        vec.checked_index_of_ptr(above),
+        Err(IndexOfError::AboveRange)
+    );
+
+    let misaligned = vec
+        .get_ptr(3)
+        .expect("is-some")
+        .cast::<u8>()
+        .wrapping_add(1)
+        .cast::<u32>();
+    assert_eq!(
+        vec.checked_index_of_ptr(misaligned),
+        Err(IndexOfError::Misaligned)
+    );
+
+    let other = 42u32;
+    let result = vec.checked_index_of_ptr(&other as *const u32);
+    assert!(matches!(
+        result,
+        Err(IndexOfError::BelowRange | IndexOfError::AboveRange)
+    ));
+}
+
+#[test]
+fn prefetch() {
+    let mut vec = TestVec::new(10);
+    vec.prefetch(0);
+    vec.prefetch(10);
+
+    for i in 0..5 {
+        vec.push(i);
+    }
+    for i in 0..20 {
+        vec.prefetch(i);
+    }
+    vec.prefetch(usize::MAX);
+    assert_eq!(vec.to_vec(), &[0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn iter_ptr_over() {
+    let vec = test_vec(50, 50);
+
+    let ptrs: Vec<_> = unsafe { vec.iter_ptr_over(10..20) }.collect();
+    assert_eq!(ptrs.len(), 10);
+    for (i, ptr) in ptrs.into_iter().enumerate() {
+        let element = vec.get(10 + i).expect("is-some");
+        assert_eq!(ptr, element as *const usize);
+        assert_eq!(unsafe { *ptr }, 10 + i);
+    }
+
+    assert_eq!(unsafe { vec.iter_ptr_over(..) }.count(), 50);
+    assert_eq!(unsafe { vec.iter_ptr_over(45..60) }.count(), 5);
+    assert_eq!(unsafe { vec.iter_ptr_over(60..) }.count(), 0);
+}
+
+#[test]
+fn iter_ptr_len() {
+    let mut vec = TestVec::new(20);
+    let (iter, len) = vec.iter_ptr_len();
+    assert_eq!(len, 0);
+    assert_eq!(iter.count(), 0);
+
+    for i in 0..15 {
+        vec.push(i);
+    }
+    let (iter, len) = vec.iter_ptr_len();
+    assert_eq!(len, vec.len());
+
+    let mut ptrs = Vec::with_capacity(len);
+    ptrs.extend(iter);
+    assert_eq!(ptrs.len(), len);
+    for (i, ptr) in ptrs.into_iter().enumerate() {
+        assert_eq!(Some(ptr), vec.get_ptr(i));
+    }
+}
+
+#[test]
+fn element_refs() {
+    let mut vec = TestVec::<usize>::new(20);
+    assert_eq!(vec.element_refs().count(), 0);
+
+    for i in 0..15 {
+        vec.push(i);
+    }
+    for (x, y) in vec.element_refs().zip(vec.iter()) {
+        assert_eq!(x as *const usize, y as *const usize);
+    }
+    assert_eq!(vec.element_refs().count(), 15);
+}
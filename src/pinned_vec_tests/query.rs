@@ -0,0 +1,126 @@
+use crate::{pinned_vec_tests::testvec::TestVec, PinnedVec};
+use alloc::string::{String, ToString};
+
+#[test]
+fn contains() {
+    let mut vec = TestVec::new(20);
+    assert!(!vec.contains(&String::from("a")));
+
+    for i in 0..10 {
+        vec.push(i.to_string());
+    }
+
+    let equal = String::from("7");
+    assert!(vec.contains(&equal));
+    assert!(!vec.contains_reference(&equal));
+
+    let clone = vec[7].clone();
+    assert!(vec.contains(&clone));
+    assert!(!vec.contains_reference(&clone));
+
+    assert!(vec.contains(&vec[3]));
+    assert!(vec.contains_reference(&vec[3]));
+
+    assert!(!vec.contains(&String::from("10")));
+}
+
+#[test]
+fn position() {
+    let mut vec = TestVec::new(20);
+    assert_eq!(vec.position(|_| true), None);
+
+    for i in 0..20 {
+        vec.push(i);
+    }
+    assert_eq!(vec.position(|x| x % 7 == 3), Some(3));
+    assert_eq!(vec.position(|x| *x > 12), Some(13));
+    assert_eq!(vec.position(|_| true), Some(0));
+    assert_eq!(vec.position(|x| *x >= 20), None);
+}
+
+#[test]
+fn rposition() {
+    let mut vec = TestVec::new(20);
+    assert_eq!(vec.rposition(|_| true), None);
+
+    for i in 0..20 {
+        vec.push(i);
+    }
+    assert_eq!(vec.rposition(|x| x % 7 == 3), Some(17));
+    assert_eq!(vec.rposition(|x| *x < 12), Some(11));
+    assert_eq!(vec.rposition(|_| true), Some(19));
+    assert_eq!(vec.rposition(|x| *x >= 20), None);
+}
+
+#[test]
+fn count_matching() {
+    let mut vec = TestVec::new(100);
+    assert_eq!(vec.count_matching(|_| true), 0);
+
+    for i in 0..100 {
+        vec.push(i);
+    }
+    assert_eq!(vec.count_matching(|x| x % 2 == 0), 50);
+    assert_eq!(vec.count_matching(|x| *x < 10), 10);
+    assert_eq!(vec.count_matching(|x| *x >= 100), 0);
+}
+
+#[test]
+fn min_max_element() {
+    let mut vec = TestVec::new(20);
+    assert_eq!(vec.min_element(), None);
+    assert_eq!(vec.max_element(), None);
+
+    for i in 0..20 {
+        vec.push((i * 7 + 3) % 20);
+    }
+    let idx_min = vec.position(|x| *x == 0).expect("is-some");
+    let idx_max = vec.position(|x| *x == 19).expect("is-some");
+
+    let min = vec.min_element().expect("is-some");
+    assert_eq!(*min, 0);
+    assert_eq!(min as *const usize, vec.get_ptr(idx_min).expect("is-some"));
+
+    let max = vec.max_element().expect("is-some");
+    assert_eq!(*max, 19);
+    assert_eq!(max as *const usize, vec.get_ptr(idx_max).expect("is-some"));
+}
+
+#[test]
+fn min_max_by_key() {
+    let mut vec = TestVec::new(20);
+    assert_eq!(vec.min_by_key(|x: &usize| *x), None);
+    assert_eq!(vec.max_by_key(|x: &usize| *x), None);
+
+    for i in 0..20 {
+        vec.push((i * 7 + 3) % 20);
+    }
+    let key = |x: &usize| (*x as i64 - 8).abs();
+    let idx_min = vec.position(|x| *x == 8).expect("is-some");
+    let idx_max = vec.position(|x| *x == 19).expect("is-some");
+
+    let min = vec.min_by_key(key).expect("is-some");
+    assert_eq!(*min, 8);
+    assert_eq!(min as *const usize, vec.get_ptr(idx_min).expect("is-some"));
+
+    let max = vec.max_by_key(key).expect("is-some");
+    assert_eq!(*max, 19);
+    assert_eq!(max as *const usize, vec.get_ptr(idx_max).expect("is-some"));
+}
+
+#[test]
+fn is_sorted() {
+    let mut vec = TestVec::new(20);
+    assert!(vec.is_sorted());
+
+    vec.push(3);
+    assert!(vec.is_sorted());
+
+    for i in [3, 4, 7, 7, 9] {
+        vec.push(i);
+    }
+    assert!(vec.is_sorted());
+
+    vec.push(8);
+    assert!(!vec.is_sorted());
+}
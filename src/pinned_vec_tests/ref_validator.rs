@@ -0,0 +1,169 @@
+use crate::PinnedVec;
+use alloc::collections::btree_map::BTreeMap;
+
+/// Records addresses of elements of a pinned vector in order to validate later that the elements have not been moved.
+///
+/// This utility allows implementors of `PinnedVec` to assert the pinned element guarantees in their own test suites:
+/// * `record` the addresses of some elements,
+/// * apply mutations which are expected to keep these elements pinned, such as `push` or `extend_from_slice`,
+/// * `validate` that the elements are still located at the recorded addresses;
+/// * `forget` the elements which are expected to be removed or moved, such as the ones beyond the truncated length.
+#[derive(Debug, PartialEq, Eq)]
+pub struct RefValidator<T> {
+    refs: BTreeMap<usize, *const T>,
+}
+
+impl<T> Default for RefValidator<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> RefValidator<T> {
+    /// Creates a new validator without any recorded references.
+    pub fn new() -> Self {
+        Self {
+            refs: BTreeMap::new(),
+        }
+    }
+
+    /// Records the address of the element at the given `index` of the `vec`;
+    /// overwrites the previously recorded address if the index had already been recorded.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `index` is out of bounds of the `vec`.
+    pub fn record<P: PinnedVec<T>>(&mut self, vec: &P, index: usize) {
+        let element = vec.get(index).expect("index is out of bounds");
+        self.refs.insert(index, element as *const T);
+    }
+
+    /// Forgets the recorded address of the element at the given `index`, if any;
+    /// validation will no longer check this index.
+    pub fn forget(&mut self, index: usize) {
+        self.refs.remove(&index);
+    }
+
+    /// Returns the recorded address of the element at the given `index`; None if it is not recorded.
+    pub fn get(&self, index: usize) -> Option<*const T> {
+        self.refs.get(&index).copied()
+    }
+
+    /// Returns the number of recorded references.
+    pub fn len(&self) -> usize {
+        self.refs.len()
+    }
+
+    /// Returns true if there exists no recorded reference.
+    pub fn is_empty(&self) -> bool {
+        self.refs.is_empty()
+    }
+
+    /// Returns an iterator of the recorded `(index, address)` pairs in ascending order of the indices.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, *const T)> + '_ {
+        self.refs.iter().map(|(i, p)| (*i, *p))
+    }
+
+    /// Validates that every recorded element of the `vec` is still located at its recorded address.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a recorded index is out of bounds of the `vec`,
+    /// or if the element at a recorded index is not located at the recorded address.
+    pub fn validate<P: PinnedVec<T>>(&self, vec: &P) {
+        for (i, addr) in &self.refs {
+            let element = vec.get(*i).expect("recorded index is out of bounds");
+            assert_eq!(*addr, element as *const T, "element address has changed");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pinned_vec_tests::testvec::TestVec;
+    use alloc::string::{String, ToString};
+
+    #[test]
+    fn record_forget() {
+        let mut vec = TestVec::new(10);
+        vec.push(String::from("a"));
+        vec.push(String::from("b"));
+
+        let mut validator = RefValidator::new();
+        assert!(validator.is_empty());
+        assert!(validator.get(0).is_none());
+
+        validator.record(&vec, 0);
+        validator.record(&vec, 1);
+        assert_eq!(validator.len(), 2);
+        assert_eq!(validator.get(0), Some(&vec[0] as *const String));
+        assert_eq!(validator.get(1), Some(&vec[1] as *const String));
+
+        validator.forget(1);
+        validator.forget(5);
+        assert_eq!(validator.len(), 1);
+        assert!(validator.get(1).is_none());
+        assert_eq!(
+            validator.iter().collect::<alloc::vec::Vec<_>>(),
+            [(0, &vec[0] as *const String)]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn record_out_of_bounds() {
+        let mut vec = TestVec::new(10);
+        vec.push(String::from("a"));
+        let mut validator = RefValidator::new();
+        validator.record(&vec, 1);
+    }
+
+    #[test]
+    fn validate() {
+        let mut vec = TestVec::new(100);
+        let mut validator = RefValidator::new();
+        for i in 0..50 {
+            vec.push(i.to_string());
+            validator.record(&vec, i);
+        }
+        validator.validate(&vec);
+
+        for i in 50..100 {
+            vec.push(i.to_string());
+        }
+        validator.validate(&vec);
+
+        vec.truncate(20);
+        for i in 20..50 {
+            validator.forget(i);
+        }
+        validator.validate(&vec);
+    }
+
+    #[test]
+    #[should_panic]
+    fn validate_out_of_bounds() {
+        let mut vec = TestVec::new(10);
+        let mut validator = RefValidator::new();
+        for i in 0..5 {
+            vec.push((i, i.to_string()));
+            validator.record(&vec, i);
+        }
+        vec.truncate(3);
+        validator.validate(&vec);
+    }
+
+    #[test]
+    #[should_panic]
+    fn validate_moved() {
+        let mut vec = TestVec::new(10);
+        vec.push(String::from("a"));
+        let mut validator = RefValidator::new();
+        validator.record(&vec, 0);
+
+        let mut other = TestVec::new(10);
+        other.push(String::from("a"));
+        validator.validate(&other);
+    }
+}
@@ -1,18 +1,24 @@
+use super::ref_validator::RefValidator;
 use crate::PinnedVec;
-use alloc::collections::btree_map::BTreeMap;
+use alloc::collections::btree_set::BTreeSet;
 use core::ops::{Deref, DerefMut};
 
-pub struct RefMap(BTreeMap<usize, Option<*const usize>>);
+/// Thin wrapper around `RefValidator<usize>` which only records the references of a pseudo-randomly selected set of indices,
+/// and additionally validates that the element at each index is equal to the index.
+pub struct RefMap {
+    indices: BTreeSet<usize>,
+    validator: RefValidator<usize>,
+}
 
 impl Deref for RefMap {
-    type Target = BTreeMap<usize, Option<*const usize>>;
+    type Target = RefValidator<usize>;
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.validator
     }
 }
 impl DerefMut for RefMap {
     fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0
+        &mut self.validator
     }
 }
 
@@ -23,44 +29,36 @@ impl RefMap {
             x % max_len
         }
 
-        let mut map = BTreeMap::new();
+        let mut indices = BTreeSet::new();
         if max_len > 0 {
             for i in 0..max_num_indices {
-                let idx = random_idx(i, max_len);
-                map.entry(idx).or_insert(None);
+                indices.insert(random_idx(i, max_len));
             }
         }
-        Self(map)
+        Self {
+            indices,
+            validator: RefValidator::new(),
+        }
     }
 
     pub fn set_reference<P: PinnedVec<usize>>(&mut self, pinned_vec: &P, i: usize) {
-        if let Some(reference) = self.get_mut(&i) {
-            let element = pinned_vec.get(i).expect("entry exists");
-            let addr = element as *const usize;
-            *reference = Some(addr);
+        if self.indices.contains(&i) {
+            self.validator.record(pinned_vec, i);
         }
     }
 
     pub fn drop_reference(&mut self, i: usize) {
-        if let Some(reference) = self.get_mut(&i) {
-            *reference = None;
-        }
+        self.validator.forget(i);
     }
 
     pub fn validate_references<P: PinnedVec<usize>>(&self, pinned_vec: &P) {
-        for (i, addr) in &self.0 {
-            if let Some(addr) = addr {
-                let element = pinned_vec.get(*i).expect("must be some");
-                assert_eq!(i, element);
-
-                let element_addr = element as *const usize;
-                assert_eq!(
-                    *addr, element_addr,
-                    "element address has changed while growing"
-                );
-                let value_at_addr = unsafe { core::ptr::read(*addr) };
-                assert_eq!(*i, value_at_addr, "value at address has changed");
-            }
+        self.validator.validate(pinned_vec);
+        for (i, addr) in self.validator.iter() {
+            let element = pinned_vec.get(i).expect("must be some");
+            assert_eq!(i, *element);
+
+            let value_at_addr = unsafe { core::ptr::read(addr) };
+            assert_eq!(i, value_at_addr, "value at address has changed");
         }
     }
 }
@@ -75,20 +73,23 @@ mod tests {
         let max_num_indices = 10;
         let max_len = 20;
         let refmap = RefMap::new(max_num_indices, max_len);
-        assert_eq!(refmap.deref(), &refmap.0);
+        assert_eq!(refmap.deref(), &refmap.validator);
     }
 
     #[test]
     fn deref_mut() {
+        let mut pinned_vec = TestVec::new(10);
+        pinned_vec.push(0);
+
         let max_num_indices = 10;
         let max_len = 1;
         let mut refmap1 = RefMap::new(max_num_indices, max_len);
         let mut refmap2 = RefMap::new(max_num_indices, max_len);
 
-        refmap1.remove(&0);
-        refmap2.0.remove(&0);
+        refmap1.record(&pinned_vec, 0);
+        refmap2.validator.record(&pinned_vec, 0);
 
-        assert_eq!(&refmap1.0, &refmap2.0);
+        assert_eq!(&refmap1.validator, &refmap2.validator);
     }
 
     #[test]
@@ -96,27 +97,32 @@ mod tests {
         let max_num_indices = 10;
         let max_len = 20;
         let refmap = RefMap::new(max_num_indices, max_len);
-        assert!(refmap.0.len() <= max_num_indices);
-        assert!(refmap.0.keys().all(|x| x < &max_len));
+        assert!(refmap.indices.len() <= max_num_indices);
+        assert!(refmap.indices.iter().all(|x| x < &max_len));
+        assert!(refmap.is_empty());
     }
 
     #[test]
     fn set_reference() {
         let mut pinned_vec = TestVec::new(10);
         pinned_vec.push(10);
+        pinned_vec.push(20);
 
         let max_num_indices = 10;
         let max_len = 1;
         let mut refmap = RefMap::new(max_num_indices, max_len);
 
-        assert!(refmap.get(&0).expect("is some").is_none());
+        assert!(refmap.get(0).is_none());
 
         refmap.set_reference(&pinned_vec, 0);
-
         assert_eq!(
-            refmap.get(&0).expect("is-some"),
-            &Some(pinned_vec.get(0).expect("is-some") as *const usize)
+            refmap.get(0),
+            Some(pinned_vec.get(0).expect("is-some") as *const usize)
         );
+
+        // index 1 is not among the selected indices
+        refmap.set_reference(&pinned_vec, 1);
+        assert!(refmap.get(1).is_none());
     }
 
     #[test]
@@ -131,17 +137,33 @@ mod tests {
 
         refmap.set_reference(&pinned_vec, 0);
         assert_eq!(
-            refmap.get(&0).expect("is-some"),
-            &Some(pinned_vec.get(0).expect("is-some") as *const usize)
+            refmap.get(0),
+            Some(pinned_vec.get(0).expect("is-some") as *const usize)
         );
 
         refmap.drop_reference(1);
         assert_eq!(
-            refmap.get(&0).expect("is-some"),
-            &Some(pinned_vec.get(0).expect("is-some") as *const usize)
+            refmap.get(0),
+            Some(pinned_vec.get(0).expect("is-some") as *const usize)
         );
 
         refmap.drop_reference(0);
-        assert!(refmap.get(&0).expect("is some").is_none());
+        assert!(refmap.get(0).is_none());
+    }
+
+    #[test]
+    fn validate_references() {
+        let mut pinned_vec = TestVec::new(100);
+        let mut refmap = RefMap::new(20, 50);
+        for i in 0..50 {
+            pinned_vec.push(i);
+            refmap.set_reference(&pinned_vec, i);
+        }
+        refmap.validate_references(&pinned_vec);
+
+        for i in 50..100 {
+            pinned_vec.push(i);
+        }
+        refmap.validate_references(&pinned_vec);
     }
 }
@@ -0,0 +1,178 @@
+use crate::{
+    pinned_vec_tests::{helpers::vecs::test_vec, testvec::TestVec},
+    PinnedVec,
+};
+use alloc::vec::Vec;
+
+#[test]
+fn pop_if() {
+    let mut vec = TestVec::new(10);
+    assert_eq!(vec.pop_if(|_| true), None);
+
+    vec.push(1);
+    vec.push(2);
+
+    assert_eq!(vec.pop_if(|x| *x == 1), None);
+    assert_eq!(vec.len(), 2);
+
+    assert_eq!(vec.pop_if(|x| *x == 2), Some(2));
+    assert_eq!(vec.len(), 1);
+
+    assert_eq!(vec.pop_if(|x| *x == 1), Some(1));
+    assert!(vec.is_empty());
+    assert_eq!(vec.pop_if(|_| true), None);
+}
+
+#[test]
+fn swap_remove_by() {
+    let mut vec = test_vec(10, 10);
+    let addresses: Vec<_> = (0..10).map(|i| vec.get_ptr(i).expect("is-some")).collect();
+
+    assert_eq!(vec.swap_remove_by(|x| *x > 5), Some(6));
+    assert_eq!(vec.len(), 9);
+    assert_eq!(vec.to_vec(), &[0, 1, 2, 3, 4, 5, 9, 7, 8]);
+    for (i, addr) in addresses.iter().take(9).enumerate() {
+        assert_eq!(vec.get_ptr(i), Some(*addr));
+    }
+
+    assert_eq!(vec.swap_remove_by(|x| *x == 8), Some(8));
+    assert_eq!(vec.to_vec(), &[0, 1, 2, 3, 4, 5, 9, 7]);
+
+    assert_eq!(vec.swap_remove_by(|x| *x > 100), None);
+    assert_eq!(vec.len(), 8);
+}
+
+#[test]
+fn swap_remove_front() {
+    let mut vec = TestVec::new(10);
+    assert_eq!(vec.swap_remove_front(), None);
+
+    for i in 0..5 {
+        vec.push(i);
+    }
+    let addresses: Vec<_> = (0..5).map(|i| vec.get_ptr(i).expect("is-some")).collect();
+
+    assert_eq!(vec.swap_remove_front(), Some(0));
+    assert_eq!(vec.len(), 4);
+    assert_eq!(vec[0], 4);
+    assert_eq!(vec.to_vec(), &[4, 1, 2, 3]);
+    for (i, addr) in addresses.iter().take(4).enumerate() {
+        assert_eq!(vec.get_ptr(i), Some(*addr));
+    }
+
+    vec.truncate(1);
+    assert_eq!(vec.swap_remove_front(), Some(4));
+    assert!(vec.is_empty());
+    assert_eq!(vec.swap_remove_front(), None);
+}
+
+#[test]
+fn keep_last() {
+    let new_vec = || test_vec(10, 10);
+
+    let mut vec = new_vec();
+    vec.keep_last(3);
+    assert_eq!(vec.to_vec(), &[7, 8, 9]);
+
+    let mut vec = new_vec();
+    vec.keep_last(0);
+    assert!(vec.is_empty());
+
+    let mut vec = new_vec();
+    vec.keep_last(20);
+    assert_eq!(vec.to_vec(), (0..10).collect::<Vec<_>>());
+
+    let mut vec = new_vec();
+    vec.keep_last(7);
+    assert_eq!(vec.to_vec(), (3..10).collect::<Vec<_>>());
+}
+
+#[test]
+fn truncate_to_keep_first() {
+    let mut vec = test_vec(10, 10);
+
+    vec.truncate_to_keep_first(20);
+    assert_eq!(vec.to_vec(), (0..10).collect::<Vec<_>>());
+
+    vec.truncate_to_keep_first(3);
+    assert_eq!(vec.to_vec(), &[0, 1, 2]);
+
+    vec.truncate_to_keep_first(0);
+    assert!(vec.is_empty());
+}
+
+#[test]
+fn truncate_reverse() {
+    use core::cell::RefCell;
+
+    struct Droppable<'a>(usize, &'a RefCell<Vec<usize>>);
+
+    impl Drop for Droppable<'_> {
+        fn drop(&mut self) {
+            self.1.borrow_mut().push(self.0);
+        }
+    }
+
+    let dropped = RefCell::new(Vec::new());
+    let mut vec = TestVec::new(10);
+    for i in 0..10 {
+        vec.push(Droppable(i, &dropped));
+    }
+
+    vec.truncate_reverse(12);
+    assert_eq!(vec.len(), 10);
+    assert!(dropped.borrow().is_empty());
+
+    vec.truncate_reverse(4);
+    assert_eq!(vec.len(), 4);
+    assert_eq!(dropped.borrow().as_slice(), &[9, 8, 7, 6, 5, 4]);
+
+    vec.truncate_reverse(0);
+    assert!(vec.is_empty());
+    assert_eq!(dropped.borrow().as_slice(), &[9, 8, 7, 6, 5, 4, 3, 2, 1, 0]);
+}
+
+#[test]
+fn truncate_count() {
+    let mut vec = test_vec(10, 10);
+
+    assert_eq!(vec.truncate_count(20), 0);
+    assert_eq!(vec.len(), 10);
+
+    assert_eq!(vec.truncate_count(10), 0);
+    assert_eq!(vec.len(), 10);
+
+    assert_eq!(vec.truncate_count(3), 7);
+    assert_eq!(vec.to_vec(), &[0, 1, 2]);
+
+    assert_eq!(vec.truncate_count(0), 3);
+    assert!(vec.is_empty());
+}
+
+#[test]
+fn clear_count() {
+    let mut vec = TestVec::new(10);
+    assert_eq!(vec.clear_count(), 0);
+
+    for i in 0..7 {
+        vec.push(i);
+    }
+    assert_eq!(vec.clear_count(), 7);
+    assert!(vec.is_empty());
+    assert_eq!(vec.clear_count(), 0);
+}
+
+#[test]
+fn clear_and_shrink() {
+    let mut vec = test_vec(100, 100);
+
+    vec.clear_and_shrink(16);
+    assert_eq!(vec.len(), 0);
+    assert!(vec.capacity() >= 16);
+    assert!(vec.capacity() < 100);
+
+    for i in 0..16 {
+        vec.push(i);
+    }
+    assert_eq!(vec.to_vec(), (0..16).collect::<Vec<_>>());
+}
@@ -0,0 +1,174 @@
+use crate::{
+    pinned_vec_tests::{
+        helpers::vecs::{frag_vec, test_vec},
+        testvec::TestVec,
+    },
+    PinnedVec,
+};
+use alloc::{string::ToString, vec::Vec};
+
+#[test]
+fn retain_mut() {
+    let mut vec = test_vec(20, 20);
+
+    vec.retain_mut(|x| {
+        let keep = *x % 2 == 0;
+        if keep {
+            *x *= 2;
+        }
+        keep
+    });
+
+    let expected: Vec<_> = (0..20).filter(|x| x % 2 == 0).map(|x| x * 2).collect();
+    assert_eq!(vec.to_vec(), expected);
+
+    vec.retain_mut(|_| false);
+    assert!(vec.is_empty());
+}
+
+#[test]
+fn retain_collect_removed() {
+    let mut vec = TestVec::new(10);
+    assert!(vec.retain_collect_removed(|_| false).is_empty());
+
+    for i in 0..10 {
+        vec.push(i);
+    }
+
+    let removed = vec.retain_collect_removed(|x| x % 2 == 0);
+    assert_eq!(removed, &[1, 3, 5, 7, 9]);
+    assert_eq!(vec.to_vec(), &[0, 2, 4, 6, 8]);
+
+    let removed = vec.retain_collect_removed(|_| true);
+    assert!(removed.is_empty());
+    assert_eq!(vec.to_vec(), &[0, 2, 4, 6, 8]);
+
+    let removed = vec.retain_collect_removed(|x| *x > 4);
+    assert_eq!(removed, &[0, 1, 2]);
+    assert_eq!(vec.to_vec(), &[6, 8]);
+}
+
+#[test]
+fn compact() {
+    let mut vec = TestVec::new(10);
+    assert_eq!(vec.compact(|_| true), 0);
+
+    for i in 0..10 {
+        vec.push((i, i % 2 == 0));
+    }
+    let addresses: Vec<_> = (0..10).map(|i| vec.get_ptr(i).expect("is-some")).collect();
+
+    let len = vec.compact(|x| x.1);
+    assert_eq!(len, 5);
+    assert_eq!(vec.len(), 5);
+    assert_eq!(
+        vec.iter().map(|x| x.0).collect::<Vec<_>>(),
+        &[0, 2, 4, 6, 8]
+    );
+    assert_eq!(vec.get_ptr(0), Some(addresses[0]));
+
+    assert_eq!(vec.compact(|x| x.0 > 4), 2);
+    assert_eq!(vec.iter().map(|x| x.0).collect::<Vec<_>>(), &[6, 8]);
+
+    assert_eq!(vec.compact(|_| false), 0);
+    assert!(vec.is_empty());
+}
+
+#[test]
+fn extract_if() {
+    let mut vec = TestVec::new(20);
+    for i in 0..20 {
+        vec.push(i.to_string());
+    }
+
+    let extracted: Vec<_> = vec
+        .extract_if(|x| x.parse::<usize>().expect("is-number") % 2 == 0)
+        .collect();
+    let expected: Vec<_> = (0..20)
+        .filter(|x| x % 2 == 0)
+        .map(|x| x.to_string())
+        .collect();
+    assert_eq!(extracted, expected);
+
+    let expected: Vec<_> = (0..20)
+        .filter(|x| x % 2 == 1)
+        .map(|x| x.to_string())
+        .collect();
+    assert_eq!(vec.to_vec(), expected);
+}
+
+#[test]
+fn extract_if_partially_consumed() {
+    let mut vec = TestVec::new(20);
+    for i in 0..20 {
+        vec.push(i.to_string());
+    }
+
+    let mut iter = vec.extract_if(|x| x.parse::<usize>().expect("is-number") % 3 == 0);
+    assert_eq!(iter.next().as_deref(), Some("0"));
+    assert_eq!(iter.next().as_deref(), Some("3"));
+    drop(iter);
+
+    let expected: Vec<_> = (0..20)
+        .filter(|x| *x != 0 && *x != 3)
+        .map(|x| x.to_string())
+        .collect();
+    assert_eq!(vec.to_vec(), expected);
+
+    assert_eq!(vec.extract_if(|_| true).count(), 18);
+    assert!(vec.is_empty());
+    assert_eq!(vec.extract_if(|_| true).count(), 0);
+}
+
+#[test]
+fn extract_if_fragmented() {
+    let mut vec = frag_vec(20);
+
+    let extracted: Vec<_> = vec.extract_if(|x| *x % 3 == 0).collect();
+    assert_eq!(extracted, [0, 3, 6, 9, 12, 15, 18]);
+    let expected: Vec<_> = (0..20).filter(|x| x % 3 != 0).collect();
+    assert_eq!(vec.to_vec(), expected);
+}
+
+#[test]
+fn dedup_consecutive_by_ptr() {
+    struct Node<'a> {
+        id: usize,
+        target: Option<&'a usize>,
+    }
+    fn key(node: &Node) -> *const () {
+        match node.target {
+            Some(x) => x as *const usize as *const (),
+            None => core::ptr::null(),
+        }
+    }
+
+    let targets = [0usize, 0, 1];
+    let [a, b, c] = [&targets[0], &targets[1], &targets[2]];
+    let links = [
+        Some(a),
+        Some(a),
+        Some(b),
+        Some(b),
+        Some(b),
+        None,
+        None,
+        Some(a),
+        Some(c),
+        Some(a),
+    ];
+
+    let mut vec = TestVec::new(10);
+    vec.dedup_consecutive_by_ptr(key);
+    for (id, target) in links.into_iter().enumerate() {
+        vec.push(Node { id, target });
+    }
+
+    vec.dedup_consecutive_by_ptr(key);
+    let ids: Vec<_> = vec.iter().map(|x| x.id).collect();
+    // a and b are equal by value but have different addresses
+    assert_eq!(ids, &[0, 2, 5, 7, 8, 9]);
+
+    vec.dedup_consecutive_by_ptr(key);
+    assert_eq!(vec.len(), 6);
+}
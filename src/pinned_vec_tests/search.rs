@@ -0,0 +1,162 @@
+use crate::{
+    pinned_vec_tests::testvec::{FragVec, TestVec},
+    PinnedVec,
+};
+
+#[test]
+fn binary_search_in() {
+    let mut vec = TestVec::new(100);
+    for i in 0..100 {
+        vec.push(i * 2);
+    }
+
+    for x in 0..210 {
+        let expected = vec.binary_search_by(|p| p.cmp(&x));
+        assert_eq!(vec.binary_search_in(.., |p| p.cmp(&x)), expected);
+    }
+
+    assert_eq!(vec.binary_search_in(40..60, |p| p.cmp(&100)), Ok(50));
+    assert_eq!(vec.binary_search_in(40..60, |p| p.cmp(&101)), Err(51));
+    assert_eq!(vec.binary_search_in(40..60, |p| p.cmp(&80)), Ok(40));
+    assert_eq!(vec.binary_search_in(40..60, |p| p.cmp(&118)), Ok(59));
+
+    assert_eq!(vec.binary_search_in(40..60, |p| p.cmp(&0)), Err(40));
+    assert_eq!(vec.binary_search_in(40..60, |p| p.cmp(&150)), Err(60));
+    assert_eq!(vec.binary_search_in(40..40, |p| p.cmp(&80)), Err(40));
+    assert_eq!(vec.binary_search_in(90..200, |p| p.cmp(&500)), Err(100));
+}
+
+#[test]
+fn binary_search_checked() {
+    let mut vec = TestVec::new(20);
+    for i in 0..10 {
+        vec.push(i * 2);
+    }
+    for i in 0..20 {
+        assert_eq!(vec.binary_search_checked(&i), vec.binary_search(&i));
+    }
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic]
+fn binary_search_checked_unsorted() {
+    let mut vec = TestVec::new(20);
+    for i in [4, 2, 7, 1] {
+        vec.push(i);
+    }
+    let _ = vec.binary_search_checked(&7);
+}
+
+#[test]
+fn binary_search_branchless() {
+    // timing is not asserted; only the results are compared with those of binary_search
+    fn validate(vec: &TestVec<u64>, value: u64) {
+        let expected = vec.binary_search(&value);
+        let result = vec.binary_search_branchless(&value);
+        match expected {
+            Ok(_) => {
+                let index = result.expect("must be found");
+                assert_eq!(vec[index], value);
+            }
+            Err(_) => assert_eq!(result, expected),
+        }
+    }
+
+    let vec = TestVec::new(0);
+    validate(&vec, 42);
+
+    let mut seed = 7u64;
+    for len in [1, 2, 3, 10, 33, 100, 1000] {
+        for max_step in [1, 3] {
+            let mut vec = TestVec::new(len);
+            let mut value = 0;
+            for _ in 0..len {
+                seed = seed
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                // steps of zero create duplicate keys
+                value += (seed >> 33) % (max_step + 1);
+                vec.push(value);
+            }
+            for x in 0..(value + 3) {
+                validate(&vec, x);
+            }
+        }
+    }
+}
+
+#[test]
+fn binary_search_branchless_fragmented() {
+    // fragmented vectors fall back to binary_search
+    for len in [0, 1, 8, 9, 20, 100] {
+        let mut vec = FragVec::new();
+        for i in 0..len {
+            vec.push(2 * i as u64);
+        }
+        assert!(len <= 8 || vec.as_slices().count() > 1);
+
+        for x in 0..(2 * len as u64 + 2) {
+            let expected = match x % 2 {
+                0 if x < 2 * len as u64 => Ok(x as usize / 2),
+                _ => Err((x.div_ceil(2) as usize).min(len)),
+            };
+            assert_eq!(vec.binary_search_branchless(&x), expected);
+        }
+    }
+}
+
+#[test]
+fn search_by() {
+    let mut vec = TestVec::new(1024);
+    let (result, num_comparisons) = vec.search_by(|x: &usize| x.cmp(&3));
+    assert_eq!((result, num_comparisons), (Err(0), 0));
+
+    for i in 0..1024 {
+        vec.push(2 * i);
+    }
+
+    for i in 0..1024 {
+        let (result, num_comparisons) = vec.search_by(|x| x.cmp(&(2 * i)));
+        assert_eq!(result, Ok(i));
+        assert!(num_comparisons <= 11);
+
+        let (result, num_comparisons) = vec.search_by(|x| x.cmp(&(2 * i + 1)));
+        assert_eq!(result, Err(i + 1));
+        assert!(num_comparisons <= 11);
+    }
+}
+
+#[test]
+fn partition_point() {
+    let mut vec = TestVec::new(10);
+    assert_eq!(vec.partition_point(|x| *x < 5), 0);
+    for i in 0..10 {
+        vec.push(i);
+    }
+    assert_eq!(vec.partition_point(|x| *x < 5), 5);
+    assert_eq!(vec.partition_point(|x| *x < 100), 10);
+    assert_eq!(vec.partition_point(|_| false), 0);
+}
+
+#[test]
+fn lower_bound_upper_bound() {
+    let mut vec = TestVec::new(10);
+    assert_eq!((vec.lower_bound(&2), vec.upper_bound(&2)), (0, 0));
+
+    for x in [1, 2, 2, 2, 3] {
+        vec.push(x);
+    }
+    assert_eq!(vec.lower_bound(&2), 1);
+    assert_eq!(vec.upper_bound(&2), 4);
+
+    assert_eq!((vec.lower_bound(&0), vec.upper_bound(&0)), (0, 0));
+    assert_eq!((vec.lower_bound(&1), vec.upper_bound(&1)), (0, 1));
+    assert_eq!((vec.lower_bound(&3), vec.upper_bound(&3)), (4, 5));
+    assert_eq!((vec.lower_bound(&7), vec.upper_bound(&7)), (5, 5));
+
+    let slot = vec.upper_bound(&2);
+    vec.insert(slot, 2);
+    assert_eq!(vec.to_vec(), &[1, 2, 2, 2, 2, 3]);
+    assert_eq!(vec.upper_bound(&2), 5);
+}
@@ -0,0 +1,37 @@
+#[cfg(test)]
+mod tests {
+    use crate::{pinned_vec_tests::testvec::TestVec, PinnedVec};
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    /// Asserts that `set_len` of the `TestVec` rejects a length beyond its capacity in debug builds.
+    ///
+    /// Only used with `TestVec`, the `set_len` of which debug asserts the bound;
+    /// with an implementation without the assertion, setting the length beyond capacity would be undefined behavior.
+    fn set_len_beyond_capacity(mut vec: TestVec<usize>) -> TestVec<usize> {
+        if cfg!(debug_assertions) {
+            let len = vec.len();
+            let invalid_len = vec.capacity() + 1;
+
+            let result = catch_unwind(AssertUnwindSafe(|| unsafe { vec.set_len(invalid_len) }));
+            assert!(result.is_err());
+            assert_eq!(vec.len(), len);
+        }
+        vec
+    }
+
+    #[test]
+    fn test_set_len_beyond_capacity_empty() {
+        let pinned_vec = TestVec::new(0);
+        set_len_beyond_capacity(pinned_vec);
+    }
+
+    #[test]
+    fn test_set_len_beyond_capacity_small() {
+        let mut pinned_vec = TestVec::new(40);
+        for i in 0..20 {
+            pinned_vec.push(i);
+        }
+        let pinned_vec = set_len_beyond_capacity(pinned_vec);
+        assert_eq!(pinned_vec.len(), 20);
+    }
+}
@@ -0,0 +1,381 @@
+use crate::{
+    pinned_vec_tests::{
+        helpers::vecs::{frag_vec, test_vec},
+        testvec::TestVec,
+    },
+    utils::slice::slices_overlap,
+    PinnedVec,
+};
+use alloc::vec::Vec;
+
+#[test]
+fn as_slices() {
+    let mut vec = TestVec::new(100);
+    assert_eq!(vec.as_slices().count(), 0);
+
+    for i in 0..100 {
+        vec.push(i);
+    }
+
+    assert_eq!(vec.as_slices().count(), 1);
+    let values: Vec<_> = vec.as_slices().flatten().copied().collect();
+    assert_eq!(values, vec.to_vec());
+}
+
+#[test]
+fn as_slices_fragmented() {
+    let mut vec = frag_vec(20);
+    let slices: Vec<_> = vec.as_slices().collect();
+    assert_eq!(slices.len(), 3);
+    assert_eq!(slices[0], (0..8).collect::<Vec<_>>());
+    assert_eq!(slices[1], (8..16).collect::<Vec<_>>());
+    assert_eq!(slices[2], (16..20).collect::<Vec<_>>());
+
+    vec.truncate(16);
+    assert_eq!(vec.num_fragments(), 3);
+    assert_eq!(vec.as_slices().count(), 2);
+
+    vec.truncate(8);
+    let slices: Vec<_> = vec.as_slices().collect();
+    assert_eq!(slices, [(0..8).collect::<Vec<_>>()]);
+
+    vec.clear();
+    assert_eq!(vec.as_slices().count(), 0);
+}
+
+#[test]
+fn as_mut_slices() {
+    let mut vec = TestVec::new(100);
+    assert_eq!(vec.as_mut_slices().count(), 0);
+
+    for _ in 0..100 {
+        vec.push(0);
+    }
+
+    let slices: Vec<_> = vec.as_mut_slices().collect();
+    for (i, a) in slices.iter().enumerate() {
+        for b in slices.iter().skip(i + 1) {
+            assert!(!slices_overlap(a, b));
+        }
+    }
+
+    let mut i = 0;
+    for s in vec.as_mut_slices() {
+        for x in s.iter_mut() {
+            *x = i * 2;
+            i += 1;
+        }
+    }
+    assert_eq!(i, 100);
+
+    for i in 0..100 {
+        assert_eq!(vec.get(i), Some(&(i * 2)));
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+#[test]
+fn as_byte_slices() {
+    let mut vec = TestVec::<u32>::new(100);
+    assert_eq!(vec.as_byte_slices().count(), 0);
+
+    for i in 0..100 {
+        vec.push(i * 1_000_003);
+    }
+    let std_vec: Vec<u32> = vec.to_vec();
+
+    let bytes: Vec<u8> = vec.as_byte_slices().flatten().copied().collect();
+    assert_eq!(bytes.len(), 400);
+    assert_eq!(bytes.as_slice(), bytemuck::cast_slice::<u32, u8>(&std_vec));
+}
+
+#[test]
+fn fragment_boundaries() {
+    let mut vec = TestVec::new(20);
+    assert_eq!(vec.fragment_boundaries().count(), 0);
+
+    for i in 0..13 {
+        vec.push(i);
+    }
+
+    let mut end = 0;
+    for range in vec.fragment_boundaries() {
+        assert_eq!(range.start, end);
+        assert!(range.end > range.start);
+        end = range.end;
+    }
+    assert_eq!(end, vec.len());
+
+    let mut ranges = vec.fragment_boundaries();
+    assert_eq!(ranges.next(), Some(0..13));
+    assert_eq!(ranges.next(), None);
+}
+
+#[test]
+fn fragment_boundaries_fragmented() {
+    let mut vec = frag_vec(20);
+    let ranges: Vec<_> = vec.fragment_boundaries().collect();
+    assert_eq!(ranges, [0..8, 8..16, 16..20]);
+
+    for (range, slice) in vec.fragment_boundaries().zip(vec.as_slices()) {
+        assert_eq!(range.len(), slice.len());
+        assert_eq!(vec.get(range.start), slice.first());
+    }
+
+    vec.truncate(16);
+    let ranges: Vec<_> = vec.fragment_boundaries().collect();
+    assert_eq!(ranges, [0..8, 8..16]);
+}
+
+#[test]
+fn try_get_ptr_range() {
+    let vec = test_vec(100, 100);
+
+    let (ptr, len) = vec.try_get_ptr_range(20..50).expect("is-some");
+    assert_eq!(ptr, vec.get_ptr(20).expect("is-some"));
+    assert_eq!(len, 30);
+    for i in 0..len {
+        assert_eq!(unsafe { *ptr.add(i) }, 20 + i);
+    }
+
+    let (ptr, len) = vec.try_get_ptr_range(0..100).expect("is-some");
+    assert_eq!(ptr, vec.get_ptr(0).expect("is-some"));
+    assert_eq!(len, 100);
+
+    assert_eq!(vec.try_get_ptr_range(30..30).map(|x| x.1), Some(0));
+
+    assert!(vec.try_get_ptr_range(90..101).is_none());
+    assert!(vec.try_get_ptr_range(101..101).is_none());
+    assert_eq!(vec.try_get_ptr_range(100..100).map(|x| x.1), Some(0));
+    #[allow(clippy::reversed_empty_ranges)]
+    let reversed = 10..5;
+    assert!(vec.try_get_ptr_range(reversed).is_none());
+}
+
+#[test]
+fn try_get_ptr_range_fragmented() {
+    let vec = frag_vec(20);
+
+    let (ptr, len) = vec
+        .try_get_ptr_range(8..16)
+        .expect("within a single fragment");
+    assert_eq!((ptr, len), (vec.get_ptr(8).expect("is-some"), 8));
+    let (ptr, len) = vec
+        .try_get_ptr_range(17..20)
+        .expect("within a single fragment");
+    assert_eq!((ptr, len), (vec.get_ptr(17).expect("is-some"), 3));
+
+    assert!(vec.try_get_ptr_range(6..10).is_none());
+    assert!(vec.try_get_ptr_range(0..20).is_none());
+    assert_eq!(vec.try_get_ptr_range(8..8).map(|x| x.1), Some(0));
+}
+
+#[test]
+fn fill_range() {
+    let mut vec = test_vec(30, 30);
+
+    vec.fill_range(10..20, 42);
+    for i in 0..30 {
+        match (10..20).contains(&i) {
+            true => assert_eq!(vec.get(i), Some(&42)),
+            false => assert_eq!(vec.get(i), Some(&i)),
+        }
+    }
+
+    vec.fill_range(25..100, 7);
+    assert_eq!(vec.to_vec_range(24..), &[24, 7, 7, 7, 7, 7]);
+
+    vec.fill_range(40..50, 0);
+    assert_eq!(vec.len(), 30);
+}
+
+#[test]
+fn split_at() {
+    let vec = test_vec(100, 100);
+
+    let (left, right) = vec.split_at(40);
+    let left: Vec<_> = left.into_iter().flatten().copied().collect();
+    let right: Vec<_> = right.into_iter().flatten().copied().collect();
+    assert_eq!(left, (0..40).collect::<Vec<_>>());
+    assert_eq!(right, (40..100).collect::<Vec<_>>());
+
+    let combined: Vec<_> = left.into_iter().chain(right).collect();
+    assert_eq!(combined, vec.to_vec());
+
+    for mid in [0, 100] {
+        let (left, right) = vec.split_at(mid);
+        let left = left.into_iter().flatten().count();
+        let right = right.into_iter().flatten().count();
+        assert_eq!((left, right), (mid, 100 - mid));
+    }
+}
+
+#[test]
+#[should_panic]
+fn split_at_out_of_bounds() {
+    let mut vec = TestVec::new(10);
+    vec.push(0);
+    let _ = vec.split_at(2);
+}
+
+#[test]
+fn split_at_mut() {
+    let mut vec = TestVec::new(100);
+    for _ in 0..100 {
+        vec.push(0);
+    }
+
+    let (left, right) = vec.split_at_mut(40);
+    left.into_iter().flatten().for_each(|x| *x = 1);
+    right.into_iter().flatten().for_each(|x| *x = 2);
+
+    for (i, x) in vec.iter().enumerate() {
+        match i < 40 {
+            true => assert_eq!(*x, 1),
+            false => assert_eq!(*x, 2),
+        }
+    }
+
+    let (left, right) = vec.split_at_mut(100);
+    assert_eq!(left.into_iter().flatten().count(), 100);
+    assert_eq!(right.into_iter().flatten().count(), 0);
+}
+
+#[test]
+fn split_at_mut_interleaved() {
+    // kept small so that it can be checked for aliasing violations with `cargo miri test`
+    let mut vec = test_vec(20, 20);
+
+    let (left, right) = vec.split_at_mut(8);
+    let mut left: Vec<_> = left.flat_map(|s| s.iter_mut()).collect();
+    let mut right: Vec<_> = right.flat_map(|s| s.iter_mut()).collect();
+    for (l, r) in left.iter_mut().zip(right.iter_mut()) {
+        core::mem::swap(*l, *r);
+    }
+    assert_eq!(left.len(), 8);
+    assert_eq!(right.len(), 12);
+
+    let expected: Vec<_> = (8..16).chain(0..8).chain(16..20).collect();
+    assert_eq!(vec.to_vec(), expected);
+}
+
+#[test]
+fn split_at_mut_fragmented() {
+    let mut vec = frag_vec(20);
+
+    let (left, right) = vec.split_at_mut(11);
+    let left: Vec<_> = left.collect();
+    let right: Vec<_> = right.collect();
+    assert_eq!(left.iter().map(|s| s.len()).collect::<Vec<_>>(), [8, 3]);
+    assert_eq!(right.iter().map(|s| s.len()).collect::<Vec<_>>(), [5, 4]);
+    for (l, r) in left
+        .into_iter()
+        .flat_map(|s| s.iter_mut())
+        .zip(right.into_iter().flat_map(|s| s.iter_mut()))
+    {
+        core::mem::swap(l, r);
+    }
+    let expected: Vec<_> = (11..20).chain(9..11).chain(0..9).collect();
+    assert_eq!(vec.to_vec(), expected);
+
+    for (mid, num_left, num_right) in [(0, 0, 3), (8, 1, 2), (16, 2, 1), (20, 3, 0)] {
+        let (left, right) = vec.split_at_mut(mid);
+        assert_eq!(left.count(), num_left);
+        assert_eq!(right.count(), num_right);
+    }
+}
+
+#[test]
+#[should_panic]
+fn split_at_mut_out_of_bounds() {
+    let mut vec = TestVec::new(10);
+    vec.push(0);
+    let _ = vec.split_at_mut(2);
+}
+
+#[test]
+fn get_disjoint_mut() {
+    let mut vec = TestVec::new(100);
+    for _ in 0..100 {
+        vec.push(0);
+    }
+
+    let [a, b] = vec.get_disjoint_mut([0..10, 20..30]).expect("is-some");
+    a.into_iter().flatten().for_each(|x| *x = 1);
+    b.into_iter().flatten().for_each(|x| *x = 2);
+    for i in 0..100 {
+        let expected = match i {
+            0..10 => 1,
+            20..30 => 2,
+            _ => 0,
+        };
+        assert_eq!(vec.get(i), Some(&expected));
+    }
+
+    assert!(vec.get_disjoint_mut([0..10, 10..20, 90..100]).is_some());
+    assert!(vec.get_disjoint_mut([0..10, 5..5]).is_some());
+
+    assert!(vec.get_disjoint_mut([0..10, 5..15]).is_none());
+    assert!(vec.get_disjoint_mut([20..30, 0..100]).is_none());
+
+    assert!(vec.get_disjoint_mut([0..10, 95..101]).is_none());
+    #[allow(clippy::reversed_empty_ranges)]
+    let reversed = 10..5;
+    assert!(vec.get_disjoint_mut([reversed]).is_none());
+}
+
+#[test]
+fn get_disjoint_mut_interleaved() {
+    // kept small so that it can be checked for aliasing violations with `cargo miri test`
+    let mut vec = test_vec(20, 20);
+
+    let [a, b, c] = vec
+        .get_disjoint_mut([0..4, 10..14, 16..20])
+        .expect("is-some");
+    let mut a: Vec<_> = a.flat_map(|s| s.iter_mut()).collect();
+    let mut b: Vec<_> = b.flat_map(|s| s.iter_mut()).collect();
+    let mut c: Vec<_> = c.flat_map(|s| s.iter_mut()).collect();
+    for ((x, y), z) in a.iter_mut().zip(b.iter_mut()).zip(c.iter_mut()) {
+        **z += **x + **y;
+        **x = 0;
+    }
+
+    let expected: Vec<_> = [0, 0, 0, 0]
+        .into_iter()
+        .chain(4..16)
+        .chain([26, 29, 32, 35])
+        .collect();
+    assert_eq!(vec.to_vec(), expected);
+}
+
+#[test]
+fn get_disjoint_mut_fragmented() {
+    let mut vec = frag_vec(30);
+
+    let views = vec
+        .get_disjoint_mut([20..27, 3..12, 12..12, 13..14])
+        .expect("is-some");
+    let [a, b, c, d] = views.map(|v| v.map(|s| s.len()).collect::<Vec<_>>());
+    assert_eq!(
+        (a, b, c, d),
+        ([4, 3].into(), [5, 4].into(), [].into(), [1].into())
+    );
+
+    let views = vec
+        .get_disjoint_mut([20..27, 3..12, 12..12, 13..14])
+        .expect("is-some");
+    for (i, view) in views.into_iter().enumerate() {
+        view.flat_map(|s| s.iter_mut()).for_each(|x| *x = 100 + i);
+    }
+    for (i, x) in vec.iter().enumerate() {
+        let expected = match i {
+            20..=26 => 100,
+            3..=11 => 101,
+            13 => 103,
+            _ => i,
+        };
+        assert_eq!(*x, expected);
+    }
+
+    assert!(vec.get_disjoint_mut([7..9, 8..17]).is_none());
+}
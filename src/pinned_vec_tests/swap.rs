@@ -0,0 +1,76 @@
+use super::refmap::RefMap;
+use crate::PinnedVec;
+
+pub fn swap<P: PinnedVec<usize>>(pinned_vec: P, max_allowed_test_len: usize) -> P {
+    let mut vec = pinned_vec;
+    vec.clear();
+
+    let mut refmap = RefMap::new(200, max_allowed_test_len);
+
+    for i in 0..max_allowed_test_len {
+        vec.push(i);
+        refmap.set_reference(&vec, i);
+    }
+
+    let addr = |vec: &P, i: usize| vec.get(i).expect("is-some") as *const usize;
+
+    let n = max_allowed_test_len;
+    let pairs = [
+        (0, n / 2),
+        (n / 3, n / 7),
+        (n / 2, n.saturating_sub(1)),
+        (n.saturating_sub(1), 0),
+        (n / 5, n / 5),
+    ];
+
+    for (a, b) in pairs.into_iter().filter(|(a, b)| *a < n && *b < n) {
+        let (addr_a, addr_b) = (addr(&vec, a), addr(&vec, b));
+
+        vec.swap(a, b);
+        assert_eq!(vec.get(a), Some(&b));
+        assert_eq!(vec.get(b), Some(&a));
+        assert_eq!(
+            addr(&vec, a),
+            addr_a,
+            "address of the slot has changed by swap"
+        );
+        assert_eq!(
+            addr(&vec, b),
+            addr_b,
+            "address of the slot has changed by swap"
+        );
+
+        vec.swap(b, a);
+        assert_eq!(vec.get(a), Some(&a));
+        assert_eq!(vec.get(b), Some(&b));
+        refmap.validate_references(&vec);
+    }
+
+    vec
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pinned_vec_tests::testvec::TestVec;
+
+    #[test]
+    fn test_swap_empty() {
+        let pinned_vec = TestVec::new(0);
+        swap(pinned_vec, 0);
+    }
+
+    #[test]
+    fn test_swap_small() {
+        let capacity = 40;
+        let pinned_vec = TestVec::new(capacity);
+        swap(pinned_vec, capacity);
+    }
+
+    #[test]
+    fn test_swap_medium() {
+        let capacity = 512;
+        let pinned_vec = TestVec::new(capacity);
+        swap(pinned_vec, capacity);
+    }
+}
@@ -0,0 +1,99 @@
+use crate::{
+    pinned_vec_tests::{
+        helpers::vecs::test_vec,
+        testvec::{FragVec, TestVec},
+    },
+    PinnedVec,
+};
+use alloc::{string::ToString, vec::Vec};
+
+#[test]
+fn try_swap() {
+    let mut vec = test_vec(10, 5);
+
+    assert!(vec.try_swap(1, 3));
+    assert_eq!(vec.to_vec(), &[0, 3, 2, 1, 4]);
+
+    assert!(!vec.try_swap(1, 5));
+    assert!(!vec.try_swap(7, 0));
+    assert_eq!(vec.to_vec(), &[0, 3, 2, 1, 4]);
+
+    assert!(vec.try_swap(2, 2));
+    assert_eq!(vec.to_vec(), &[0, 3, 2, 1, 4]);
+}
+
+#[test]
+fn swap_with_slice() {
+    let mut vec = TestVec::<usize>::new(5);
+    for i in 0..5 {
+        vec.push(i);
+    }
+    let first = vec.get(0).expect("is-some") as *const usize;
+
+    let mut array = [10, 11, 12, 13, 14];
+    vec.swap_with_slice(&mut array);
+
+    assert_eq!(vec.to_vec(), &[10, 11, 12, 13, 14]);
+    assert_eq!(array, [0, 1, 2, 3, 4]);
+    assert_eq!(vec.get(0).expect("is-some") as *const usize, first);
+}
+
+#[test]
+#[should_panic]
+fn swap_with_slice_different_lengths() {
+    let mut vec = test_vec(5, 5);
+    vec.swap_with_slice(&mut [0, 1, 2]);
+}
+
+#[test]
+fn swap_ranges_crossing() {
+    let mut vec = FragVec::new();
+    for i in 0..32 {
+        vec.push(i.to_string());
+    }
+    assert_eq!(vec.num_fragments(), 4);
+    let addresses: Vec<_> = (0..32).map(|i| vec.get_ptr(i).expect("is-some")).collect();
+
+    // both ranges straddle fragment boundaries, at positions 8 and 24
+    vec.swap_ranges_crossing(6..10, 22..26);
+    let expected: Vec<_> = (0..6)
+        .chain(22..26)
+        .chain(10..22)
+        .chain(6..10)
+        .chain(26..32)
+        .map(|i| i.to_string())
+        .collect();
+    assert_eq!(vec.to_vec(), expected);
+    for (i, addr) in addresses.iter().enumerate() {
+        assert_eq!(vec.get_ptr(i), Some(*addr));
+    }
+
+    vec.swap_ranges_crossing(22..26, 6..10);
+    let expected: Vec<_> = (0..32).map(|i| i.to_string()).collect();
+    assert_eq!(vec.to_vec(), expected);
+
+    vec.swap_ranges_crossing(15..15, 15..15);
+    vec.swap_ranges_crossing(3..3, 32..32);
+    assert_eq!(vec.to_vec(), expected);
+}
+
+#[test]
+#[should_panic]
+fn swap_ranges_crossing_different_lengths() {
+    let mut vec = test_vec(10, 10);
+    vec.swap_ranges_crossing(0..2, 5..8);
+}
+
+#[test]
+#[should_panic]
+fn swap_ranges_crossing_overlapping() {
+    let mut vec = test_vec(10, 10);
+    vec.swap_ranges_crossing(2..6, 4..8);
+}
+
+#[test]
+#[should_panic]
+fn swap_ranges_crossing_out_of_bounds() {
+    let mut vec = test_vec(10, 10);
+    vec.swap_ranges_crossing(0..3, 8..11);
+}
@@ -6,10 +6,13 @@ use crate::PinnedVec;
 ///
 /// * `pinned.push(new_element)`: does not change the memory locations of the first `n` elements;
 /// * `pinned.extend_from_slice(slice)`: does not change the memory locations of the first `n` elements;
+/// * `pinned.grow_and_initialize(capacity, fill)`: does not change the memory locations of the first `n` elements;
 /// * `pinned.insert(a, new_element)`: does not change the memory locations of the first `a` elements, where `a <= n`; elements to the right of the inserted element might be changed (commonly shifted to right).
 /// * `pinned.pop()`: does not change the memory locations of the first `n-1` elements (the n-th element will be removed);
 /// * `pinned.remove(a)`: does not change the memory locations of the first `a` elements, where `a < n`; elements to the right of the removed element might be changed (commonly shifted to left).
-/// * `pinned.truncate(a)`: does not change the memory locations of the first `a` elements, where `a < n`.
+/// * `pinned.truncate(a)`: does not change the memory locations of the first `a` elements, where `a < n`;
+/// * `pinned.iter_rev()` and `pinned.iter_mut_rev()`: visit all `n` elements in reverse order, and do not change their memory locations;
+/// * `pinned.swap(a, b)`: exchanges the values at positions `a` and `b`, and does not change the memory locations of any of the `n` elements.
 ///
 /// # Panics
 ///
@@ -17,32 +20,39 @@ use crate::PinnedVec;
 pub fn test_pinned_vec<P: PinnedVec<usize>>(pinned_vec: P, test_vec_len: usize) {
     let pinned_vec = super::push::push(pinned_vec, test_vec_len);
     let pinned_vec = super::extend::extend(pinned_vec, test_vec_len);
+    let pinned_vec = super::grow_and_initialize::grow(pinned_vec, test_vec_len);
     let pinned_vec = super::insert::insert(pinned_vec, test_vec_len);
     let pinned_vec = super::pop::pop(pinned_vec, test_vec_len);
     let pinned_vec = super::remove::remove(pinned_vec, test_vec_len);
     let pinned_vec = super::truncate::truncate(pinned_vec, test_vec_len);
     let pinned_vec = super::slices::slices(pinned_vec, test_vec_len);
+    let pinned_vec = super::iter_rev::iter_rev(pinned_vec, test_vec_len);
+    let pinned_vec = super::swap::swap(pinned_vec, test_vec_len);
     let pinned_vec = super::binary_search::binary_search(pinned_vec, test_vec_len);
     let _ = super::unsafe_writer::unsafe_writer(pinned_vec, test_vec_len);
 }
 
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
     use super::*;
     use crate::{
-        pinned_vec_tests::helpers::range::{range_end, range_start},
+        pinned_vec_tests::{
+            helpers::range::{range_end, range_start},
+            testvec::FragVec,
+        },
         utils, CapacityState,
     };
     use alloc::vec::Vec;
     use core::{
         cmp::Ordering,
         iter::Rev,
+        mem::MaybeUninit,
         ops::{Index, IndexMut, RangeBounds},
     };
     use orx_pseudo_default::PseudoDefault;
 
     #[derive(Debug)]
-    struct JustVec<T>(Vec<T>);
+    pub(crate) struct JustVec<T>(pub(crate) Vec<T>);
 
     impl<T> PseudoDefault for JustVec<T> {
         fn pseudo_default() -> Self {
@@ -160,6 +170,10 @@ mod tests {
             CapacityState::FixedCapacity(PinnedVec::capacity(self))
         }
 
+        fn capacity_bound(&self) -> usize {
+            PinnedVec::capacity(self)
+        }
+
         fn extend_from_slice(&mut self, other: &[T])
         where
             T: Clone,
@@ -283,9 +297,17 @@ mod tests {
         }
 
         unsafe fn set_len(&mut self, new_len: usize) {
+            debug_assert!(new_len <= PinnedVec::capacity(self));
             self.0.set_len(new_len)
         }
 
+        fn spare_capacity_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut MaybeUninit<T>> + 'a
+        where
+            T: 'a,
+        {
+            self.0.spare_capacity_mut().iter_mut()
+        }
+
         fn binary_search_by<F>(&self, f: F) -> Result<usize, usize>
         where
             F: FnMut(&T) -> Ordering,
@@ -329,6 +351,13 @@ mod tests {
         test_pinned_vec(vec, capacity);
     }
 
+    #[test]
+    fn fragmented_vec_passes() {
+        for len in [0, 7, 8, 9, 130, 1025] {
+            test_pinned_vec(FragVec::new(), len);
+        }
+    }
+
     #[test]
     #[should_panic]
     fn capacity_exceeding_vec_fails() {
@@ -0,0 +1,273 @@
+use super::ref_validator::RefValidator;
+use crate::PinnedVec;
+use alloc::vec::Vec;
+
+/// Tests the pinned vector guarantees of the specific `PinnedVec` implementation `P` with elements of an arbitrary type `T`.
+/// Assertions fail if any of the guarantees are not satisfied.
+///
+/// This is the counterpart of `test_pinned_vec` which is limited to `usize` elements;
+/// it runs the push, extend, insert, pop, remove, truncate and binary search tests with elements created by `make_element`.
+///
+/// `make_element(i)` is used to create the element that is expected to be at position `i` of the vector;
+/// it is required to be strictly increasing in `i`, so that the created elements are distinct and sorted.
+///
+/// # Panics
+///
+/// Panics if the pinned vector implementation `P` does not satisfy pinned elements guarantees.
+pub fn test_pinned_vec_generic<T, P, F>(pinned_vec: P, test_vec_len: usize, make_element: F)
+where
+    T: Clone + Ord + Eq,
+    P: PinnedVec<T>,
+    F: Fn(usize) -> T,
+{
+    let pinned_vec = push(pinned_vec, test_vec_len, &make_element);
+    let pinned_vec = extend(pinned_vec, test_vec_len, &make_element);
+    let pinned_vec = insert(pinned_vec, test_vec_len, &make_element);
+    let pinned_vec = pop(pinned_vec, test_vec_len, &make_element);
+    let pinned_vec = remove(pinned_vec, test_vec_len, &make_element);
+    let pinned_vec = truncate(pinned_vec, test_vec_len, &make_element);
+    let _ = binary_search(pinned_vec, test_vec_len, &make_element);
+}
+
+/// Returns true if the address of the element at position `i` is to be recorded; around 200 positions out of `len` are sampled.
+fn is_sampled(i: usize, len: usize) -> bool {
+    let step = (len / 200).max(1);
+    matches!(i % step, 0)
+}
+
+fn fill<T, P, F>(vec: &mut P, len: usize, make_element: &F)
+where
+    P: PinnedVec<T>,
+    F: Fn(usize) -> T,
+{
+    vec.clear();
+    for i in 0..len {
+        vec.push(make_element(i));
+    }
+}
+
+fn assert_values<T, P, F>(vec: &P, len: usize, make_element: &F)
+where
+    T: Eq,
+    P: PinnedVec<T>,
+    F: Fn(usize) -> T,
+{
+    assert_eq!(vec.len(), len);
+    for i in 0..len {
+        assert!(vec[i] == make_element(i), "value at position has changed");
+    }
+}
+
+fn push<T, P, F>(pinned_vec: P, max_allowed_test_len: usize, make_element: &F) -> P
+where
+    T: Eq,
+    P: PinnedVec<T>,
+    F: Fn(usize) -> T,
+{
+    let mut vec = pinned_vec;
+    vec.clear();
+
+    let mut validator = RefValidator::new();
+
+    for i in 0..max_allowed_test_len {
+        vec.push(make_element(i));
+        if is_sampled(i, max_allowed_test_len) {
+            validator.record(&vec, i);
+        }
+        validator.validate(&vec);
+    }
+    assert_values(&vec, max_allowed_test_len, make_element);
+
+    vec
+}
+
+fn extend<T, P, F>(pinned_vec: P, max_allowed_test_len: usize, make_element: &F) -> P
+where
+    T: Clone + Eq,
+    P: PinnedVec<T>,
+    F: Fn(usize) -> T,
+{
+    let mut vec = pinned_vec;
+    vec.clear();
+
+    let mut validator = RefValidator::new();
+
+    let extend_len = (max_allowed_test_len / 37).max(1);
+    while vec.len() < max_allowed_test_len {
+        let begin = vec.len();
+        let end = (begin + extend_len).min(max_allowed_test_len);
+        let slice: Vec<_> = (begin..end).map(make_element).collect();
+        vec.extend_from_slice(&slice);
+
+        for i in (begin..end).filter(|i| is_sampled(*i, max_allowed_test_len)) {
+            validator.record(&vec, i);
+        }
+        validator.validate(&vec);
+    }
+    assert_values(&vec, max_allowed_test_len, make_element);
+
+    vec
+}
+
+fn insert<T, P, F>(pinned_vec: P, max_allowed_test_len: usize, make_element: &F) -> P
+where
+    T: Eq,
+    P: PinnedVec<T>,
+    F: Fn(usize) -> T,
+{
+    let mut vec = pinned_vec;
+    vec.clear();
+
+    let first_half = max_allowed_test_len / 2;
+
+    let mut validator = RefValidator::new();
+
+    for i in 0..first_half {
+        vec.push(make_element(i));
+        if is_sampled(i, first_half) {
+            validator.record(&vec, i);
+        }
+    }
+
+    for i in first_half..max_allowed_test_len {
+        vec.insert(first_half, make_element(i));
+        validator.validate(&vec);
+    }
+    for i in 0..first_half {
+        assert!(vec[i] == make_element(i), "value at position has changed");
+    }
+
+    vec
+}
+
+fn pop<T, P, F>(pinned_vec: P, max_allowed_test_len: usize, make_element: &F) -> P
+where
+    T: Eq,
+    P: PinnedVec<T>,
+    F: Fn(usize) -> T,
+{
+    let mut vec = pinned_vec;
+    fill(&mut vec, max_allowed_test_len, make_element);
+
+    let mut validator = RefValidator::new();
+    for i in (0..max_allowed_test_len).filter(|i| is_sampled(*i, max_allowed_test_len)) {
+        validator.record(&vec, i);
+    }
+
+    for i in (0..max_allowed_test_len).rev() {
+        let value = vec.pop().expect("is some");
+        assert!(
+            value == make_element(i),
+            "popped value is not the last element"
+        );
+        validator.forget(i);
+        validator.validate(&vec);
+    }
+    assert!(vec.pop().is_none());
+
+    vec
+}
+
+fn remove<T, P, F>(pinned_vec: P, max_allowed_test_len: usize, make_element: &F) -> P
+where
+    T: Eq,
+    P: PinnedVec<T>,
+    F: Fn(usize) -> T,
+{
+    let mut vec = pinned_vec;
+    fill(&mut vec, max_allowed_test_len, make_element);
+
+    let first_half = max_allowed_test_len / 2;
+
+    let mut validator = RefValidator::new();
+    for i in (0..first_half).filter(|i| is_sampled(*i, first_half)) {
+        validator.record(&vec, i);
+    }
+
+    for i in first_half..max_allowed_test_len {
+        let removed = vec.remove(first_half);
+        assert!(
+            removed == make_element(i),
+            "removed value is not the expected element"
+        );
+        validator.validate(&vec);
+    }
+    assert_values(&vec, first_half, make_element);
+
+    vec
+}
+
+fn truncate<T, P, F>(pinned_vec: P, max_allowed_test_len: usize, make_element: &F) -> P
+where
+    T: Eq,
+    P: PinnedVec<T>,
+    F: Fn(usize) -> T,
+{
+    let mut vec = pinned_vec;
+    fill(&mut vec, max_allowed_test_len, make_element);
+
+    let first_half = max_allowed_test_len / 2;
+
+    let mut validator = RefValidator::new();
+    for i in (0..first_half).filter(|i| is_sampled(*i, first_half)) {
+        validator.record(&vec, i);
+    }
+
+    for _ in first_half..max_allowed_test_len {
+        let new_len = vec.len() - 1;
+        vec.truncate(new_len);
+        validator.validate(&vec);
+    }
+    assert_values(&vec, first_half, make_element);
+
+    vec
+}
+
+fn binary_search<T, P, F>(pinned_vec: P, max_allowed_test_len: usize, make_element: &F) -> P
+where
+    T: Ord,
+    P: PinnedVec<T>,
+    F: Fn(usize) -> T,
+{
+    let mut vec = pinned_vec;
+    fill(&mut vec, max_allowed_test_len, make_element);
+
+    for i in (0..max_allowed_test_len).filter(|i| is_sampled(*i, max_allowed_test_len)) {
+        let value = make_element(i);
+        assert_eq!(vec.binary_search(&value), Ok(i));
+        assert_eq!(vec.binary_search_by(|x| x.cmp(&value)), Ok(i));
+    }
+
+    vec
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pinned_vec_tests::testvec::{FragVec, TestVec};
+    use alloc::{format, string::String};
+
+    #[test]
+    fn test_pinned_vec_generic_tuples() {
+        for cap in [0, 10, 124, 5421] {
+            let make_element = |i: usize| ((i / 3) as u32, (i % 3) as u32);
+            test_pinned_vec_generic(TestVec::<(u32, u32)>::new(cap), cap, make_element);
+        }
+    }
+
+    #[test]
+    fn test_pinned_vec_generic_strings() {
+        for cap in [0, 10, 124, 5421] {
+            let make_element = |i: usize| format!("{:08}", i);
+            test_pinned_vec_generic(TestVec::<String>::new(cap), cap, make_element);
+        }
+    }
+
+    #[test]
+    fn test_pinned_vec_generic_fragmented() {
+        for len in [0, 10, 124, 1025] {
+            let make_element = |i: usize| format!("{:08}", i);
+            test_pinned_vec_generic(FragVec::<String>::new(), len, make_element);
+        }
+    }
+}
@@ -1,25 +1,33 @@
 use super::helpers::range::{range_end, range_start};
-use crate::*;
+use crate::{utils::slice::vec_range_limits, *};
 use alloc::vec::Vec;
 use core::{
     cmp::Ordering,
     iter::Rev,
+    mem::MaybeUninit,
     ops::{Index, IndexMut, RangeBounds},
+    sync::atomic::{AtomicPtr, AtomicUsize, Ordering as AtomicOrdering},
 };
 use orx_pseudo_default::PseudoDefault;
 
-pub struct TestVec<T>(Vec<T>);
+/// Fixed capacity pinned vector for tests; the second field counts the calls to `reserve`.
+pub struct TestVec<T>(Vec<T>, usize);
 
 impl<T> PseudoDefault for TestVec<T> {
     fn pseudo_default() -> Self {
-        Self(Default::default())
+        Self(Default::default(), 0)
     }
 }
 
 impl<T> TestVec<T> {
     #[cfg(test)]
     pub fn new(capacity: usize) -> Self {
-        Self(Vec::with_capacity(capacity))
+        Self(Vec::with_capacity(capacity), 0)
+    }
+
+    #[cfg(test)]
+    pub fn num_reserve_calls(&self) -> usize {
+        self.1
     }
 
     fn assert_has_room(&self, required_additional_space: usize) {
@@ -123,6 +131,11 @@ impl<T> PinnedVec<T> for TestVec<T> {
         self.0.clear();
     }
 
+    fn clear_and_shrink(&mut self, keep_capacity: usize) {
+        self.0.clear();
+        self.0.shrink_to(keep_capacity);
+    }
+
     fn capacity(&self) -> usize {
         self.0.capacity()
     }
@@ -131,6 +144,18 @@ impl<T> PinnedVec<T> for TestVec<T> {
         CapacityState::FixedCapacity(PinnedVec::capacity(self))
     }
 
+    fn capacity_bound(&self) -> usize {
+        PinnedVec::capacity(self)
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.1 += 1;
+        // growing is only possible while there exist no elements to keep pinned
+        if self.0.is_empty() {
+            self.0.reserve(additional)
+        }
+    }
+
     fn extend_from_slice(&mut self, other: &[T])
     where
         T: Clone,
@@ -254,9 +279,17 @@ impl<T> PinnedVec<T> for TestVec<T> {
     }
 
     unsafe fn set_len(&mut self, new_len: usize) {
+        debug_assert!(new_len <= PinnedVec::capacity(self));
         self.0.set_len(new_len)
     }
 
+    fn spare_capacity_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut MaybeUninit<T>> + 'a
+    where
+        T: 'a,
+    {
+        self.0.spare_capacity_mut().iter_mut()
+    }
+
     fn binary_search_by<F>(&self, f: F) -> Result<usize, usize>
     where
         F: FnMut(&T) -> Ordering,
@@ -286,3 +319,872 @@ impl<T> PinnedVec<T> for TestVec<T> {
         self.0.sort_by_key(f)
     }
 }
+
+impl<T> IntoConcurrentPinnedVec<T> for TestVec<T> {
+    type ConPinnedVec = ConTestVec<T>;
+
+    fn into_concurrent(self) -> Self::ConPinnedVec {
+        ConTestVec::new(self)
+    }
+
+    fn into_concurrent_filled_with<F>(mut self, fill_with: F) -> Self::ConPinnedVec
+    where
+        F: Fn() -> T,
+    {
+        while self.0.len() < self.0.capacity() {
+            self.0.push(fill_with());
+        }
+        ConTestVec::new(self)
+    }
+}
+
+pub struct ConTestVec<T> {
+    vec: TestVec<T>,
+    ptr: *mut T,
+    capacity: usize,
+}
+
+unsafe impl<T: Send> Send for ConTestVec<T> {}
+
+unsafe impl<T: Send + Sync> Sync for ConTestVec<T> {}
+
+impl<T> ConTestVec<T> {
+    fn new(mut vec: TestVec<T>) -> Self {
+        let ptr = vec.0.as_mut_ptr();
+        let capacity = vec.0.capacity();
+        Self { vec, ptr, capacity }
+    }
+
+    fn range_limits<R: RangeBounds<usize>>(&self, range: &R) -> [usize; 2] {
+        vec_range_limits(range, Some(self.capacity))
+    }
+}
+
+impl<T> ConcurrentPinnedVec<T> for ConTestVec<T> {
+    type P = TestVec<T>;
+
+    unsafe fn into_inner(mut self, len: usize) -> Self::P {
+        self.vec.0.set_len(len);
+        self.vec
+    }
+
+    unsafe fn clone_with_len(&self, len: usize) -> Self
+    where
+        T: Clone,
+    {
+        let mut vec = Vec::with_capacity(self.capacity);
+        vec.extend(self.iter(len).cloned());
+        Self::new(TestVec(vec, 0))
+    }
+
+    unsafe fn iter<'a>(&'a self, len: usize) -> impl Iterator<Item = &'a T> + 'a
+    where
+        T: 'a,
+    {
+        core::slice::from_raw_parts(self.ptr as *const T, len).iter()
+    }
+
+    unsafe fn iter_over_range<'a, R: RangeBounds<usize>>(
+        &'a self,
+        range: R,
+    ) -> impl Iterator<Item = &'a T> + 'a
+    where
+        T: 'a,
+    {
+        let [a, b] = self.range_limits(&range);
+        core::slice::from_raw_parts(self.ptr.add(a) as *const T, b - a).iter()
+    }
+
+    unsafe fn get(&self, index: usize) -> Option<&T> {
+        (index < self.capacity).then(|| &*self.ptr.add(index))
+    }
+
+    unsafe fn get_ptr_mut(&self, index: usize) -> *mut T {
+        assert!(index < self.capacity, "index is out of capacity bounds");
+        self.ptr.add(index)
+    }
+
+    unsafe fn slices_mut<R: RangeBounds<usize>>(
+        &self,
+        range: R,
+    ) -> <Self::P as PinnedVec<T>>::SliceMutIter<'_> {
+        let [a, b] = self.range_limits(&range);
+        Some(core::slice::from_raw_parts_mut(self.ptr.add(a), b - a))
+    }
+
+    fn slices<R: RangeBounds<usize>>(&self, range: R) -> <Self::P as PinnedVec<T>>::SliceIter<'_> {
+        let [a, b] = self.range_limits(&range);
+        Some(unsafe { core::slice::from_raw_parts(self.ptr.add(a) as *const T, b - a) })
+    }
+
+    fn max_capacity(&self) -> usize {
+        self.capacity
+    }
+
+    fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    fn grow_to(&self, new_capacity: usize) -> Result<usize, PinnedVecGrowthError> {
+        match new_capacity <= self.capacity {
+            true => Ok(self.capacity),
+            false => Err(PinnedVecGrowthError::FailedToGrowWhileKeepingElementsPinned),
+        }
+    }
+
+    fn grow_to_and_fill_with<F>(
+        &self,
+        new_capacity: usize,
+        _: F,
+    ) -> Result<usize, PinnedVecGrowthError>
+    where
+        F: Fn() -> T,
+    {
+        self.grow_to(new_capacity)
+    }
+
+    fn fill_with<F>(&self, range: core::ops::Range<usize>, fill_with: F)
+    where
+        F: Fn() -> T,
+    {
+        for i in range {
+            unsafe { self.get_ptr_mut(i).write(fill_with()) };
+        }
+    }
+
+    unsafe fn reserve_maximum_concurrent_capacity(
+        &mut self,
+        len: usize,
+        new_maximum_capacity: usize,
+    ) -> usize {
+        self.vec.0.set_len(len);
+        self.vec
+            .0
+            .reserve_exact(new_maximum_capacity.saturating_sub(len));
+        self.ptr = self.vec.0.as_mut_ptr();
+        self.capacity = self.vec.0.capacity();
+        self.capacity
+    }
+
+    unsafe fn reserve_maximum_concurrent_capacity_fill_with<F>(
+        &mut self,
+        len: usize,
+        new_maximum_capacity: usize,
+        fill_with: F,
+    ) -> usize
+    where
+        F: Fn() -> T,
+    {
+        let old_capacity = self.capacity;
+        let capacity = self.reserve_maximum_concurrent_capacity(len, new_maximum_capacity);
+        self.fill_with(old_capacity..capacity, fill_with);
+        capacity
+    }
+
+    unsafe fn set_pinned_vec_len(&mut self, len: usize) {
+        self.vec.0.set_len(len);
+    }
+
+    unsafe fn iter_mut<'a>(&'a mut self, len: usize) -> impl Iterator<Item = &'a mut T> + 'a
+    where
+        T: 'a,
+    {
+        core::slice::from_raw_parts_mut(self.ptr, len).iter_mut()
+    }
+
+    unsafe fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        (index < self.capacity).then(|| &mut *self.ptr.add(index))
+    }
+
+    unsafe fn clear(&mut self, len: usize) {
+        self.vec.0.set_len(len);
+        self.vec.0.clear();
+    }
+}
+
+/// Number of elements of each fragment of the `FragVec`.
+pub const FRAGMENT_LEN: usize = 8;
+
+/// Growable fragmented pinned vector for tests, which stores the elements in fragments of exactly `FRAGMENT_LEN` elements;
+/// fragment `f` holds the elements at positions `f * FRAGMENT_LEN..(f + 1) * FRAGMENT_LEN`.
+///
+/// Growth never moves the existing elements since it only allocates new fragments.
+/// It is used to exercise the fragmented branches of the default `PinnedVec` methods.
+pub struct FragVec<T> {
+    fragments: Vec<Vec<T>>,
+    len: usize,
+}
+
+impl<T> PseudoDefault for FragVec<T> {
+    fn pseudo_default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> FragVec<T> {
+    pub fn new() -> Self {
+        Self {
+            fragments: Vec::new(),
+            len: 0,
+        }
+    }
+
+    pub fn num_fragments(&self) -> usize {
+        self.fragments.len()
+    }
+
+    fn location(index: usize) -> (usize, usize) {
+        (index / FRAGMENT_LEN, index % FRAGMENT_LEN)
+    }
+
+    /// Sets the lengths of the fragments so that they hold the positions `0..len`.
+    ///
+    /// # Safety
+    ///
+    /// `len` must not exceed the capacity; and all positions `0..len` must be initialized.
+    unsafe fn set_fragment_lengths(&mut self, len: usize) {
+        for (f, fragment) in self.fragments.iter_mut().enumerate() {
+            let fragment_len = len.saturating_sub(f * FRAGMENT_LEN).min(FRAGMENT_LEN);
+            fragment.set_len(fragment_len);
+        }
+        self.len = len;
+    }
+
+    fn slices_range<R: RangeBounds<usize>>(&self, range: &R) -> Option<[usize; 2]> {
+        let a = range_start(range);
+        let b = range_end(range, self.len);
+        (a < b && b <= self.len).then_some([a, b])
+    }
+}
+
+impl<T> Index<usize> for FragVec<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        self.get(index).expect("index is out of bounds")
+    }
+}
+
+impl<T> IndexMut<usize> for FragVec<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        self.get_mut(index).expect("index is out of bounds")
+    }
+}
+
+impl<T> IntoIterator for FragVec<T> {
+    type Item = T;
+    type IntoIter = core::iter::Flatten<alloc::vec::IntoIter<Vec<T>>>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.fragments.into_iter().flatten()
+    }
+}
+
+impl<T> PinnedVec<T> for FragVec<T> {
+    type Iter<'a>
+        = core::iter::Flatten<core::slice::Iter<'a, Vec<T>>>
+    where
+        T: 'a,
+        Self: 'a;
+    type IterMut<'a>
+        = core::iter::Flatten<core::slice::IterMut<'a, Vec<T>>>
+    where
+        T: 'a,
+        Self: 'a;
+    type IterRev<'a>
+        = Rev<Self::Iter<'a>>
+    where
+        T: 'a,
+        Self: 'a;
+    type IterMutRev<'a>
+        = Rev<Self::IterMut<'a>>
+    where
+        T: 'a,
+        Self: 'a;
+    type SliceIter<'a>
+        = Vec<&'a [T]>
+    where
+        T: 'a,
+        Self: 'a;
+    type SliceMutIter<'a>
+        = Vec<&'a mut [T]>
+    where
+        T: 'a,
+        Self: 'a;
+
+    fn index_of(&self, data: &T) -> Option<usize> {
+        self.index_of_ptr(data as *const T)
+    }
+
+    fn index_of_ptr(&self, element_ptr: *const T) -> Option<usize> {
+        self.fragments.iter().enumerate().find_map(|(f, fragment)| {
+            utils::slice::index_of_ptr(fragment, element_ptr).map(|i| f * FRAGMENT_LEN + i)
+        })
+    }
+
+    fn push_get_ptr(&mut self, value: T) -> *const T {
+        let index = self.len;
+        self.push(value);
+        self.get_ptr(index).expect("is pushed")
+    }
+
+    unsafe fn iter_ptr<'v, 'i>(&'v self) -> impl Iterator<Item = *const T> + 'i
+    where
+        T: 'i,
+    {
+        let ptrs: Vec<_> = self.iter().map(|x| x as *const T).collect();
+        ptrs.into_iter()
+    }
+
+    unsafe fn iter_ptr_rev<'v, 'i>(&'v self) -> impl Iterator<Item = *const T> + 'i
+    where
+        T: 'i,
+    {
+        let ptrs: Vec<_> = self.iter_rev().map(|x| x as *const T).collect();
+        ptrs.into_iter()
+    }
+
+    fn contains_reference(&self, element: &T) -> bool {
+        self.index_of(element).is_some()
+    }
+
+    fn contains_ptr(&self, element_ptr: *const T) -> bool {
+        self.index_of_ptr(element_ptr).is_some()
+    }
+
+    fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    fn clear_and_shrink(&mut self, keep_capacity: usize) {
+        self.truncate(0);
+        self.fragments
+            .truncate(keep_capacity.div_ceil(FRAGMENT_LEN));
+    }
+
+    fn capacity(&self) -> usize {
+        self.fragments.len() * FRAGMENT_LEN
+    }
+
+    fn capacity_state(&self) -> CapacityState {
+        CapacityState::DynamicCapacity {
+            current_capacity: PinnedVec::capacity(self),
+            maximum_concurrent_capacity: PinnedVec::capacity(self),
+        }
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        while PinnedVec::capacity(self) < self.len + additional {
+            self.fragments.push(Vec::with_capacity(FRAGMENT_LEN));
+        }
+    }
+
+    fn grow_and_initialize<F>(
+        &mut self,
+        min_capacity: usize,
+        mut fill: F,
+    ) -> Result<usize, PinnedVecGrowthError>
+    where
+        F: FnMut() -> T,
+    {
+        self.reserve(min_capacity.saturating_sub(self.len));
+        while self.len < PinnedVec::capacity(self) {
+            self.push(fill());
+        }
+        Ok(PinnedVec::capacity(self))
+    }
+
+    fn extend_from_slice(&mut self, other: &[T])
+    where
+        T: Clone,
+    {
+        for x in other {
+            self.push(x.clone());
+        }
+    }
+
+    fn get(&self, index: usize) -> Option<&T> {
+        let (f, i) = Self::location(index);
+        self.fragments.get(f).and_then(|fragment| fragment.get(i))
+    }
+
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        let (f, i) = Self::location(index);
+        self.fragments
+            .get_mut(f)
+            .and_then(|fragment| fragment.get_mut(i))
+    }
+
+    unsafe fn get_unchecked(&self, index: usize) -> &T {
+        &self[index]
+    }
+
+    unsafe fn get_unchecked_mut(&mut self, index: usize) -> &mut T {
+        &mut self[index]
+    }
+
+    fn first(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    fn last(&self) -> Option<&T> {
+        self.len.checked_sub(1).and_then(|i| self.get(i))
+    }
+
+    unsafe fn first_unchecked(&self) -> &T {
+        &self[0]
+    }
+
+    unsafe fn last_unchecked(&self) -> &T {
+        &self[self.len - 1]
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn push(&mut self, value: T) {
+        let (f, _) = Self::location(self.len);
+        if f == self.fragments.len() {
+            self.fragments.push(Vec::with_capacity(FRAGMENT_LEN));
+        }
+        self.fragments[f].push(value);
+        self.len += 1;
+    }
+
+    fn insert(&mut self, index: usize, element: T) {
+        assert!(index <= self.len, "index is out of bounds");
+        self.push(element);
+        for i in (index..self.len - 1).rev() {
+            self.swap(i, i + 1);
+        }
+    }
+
+    fn remove(&mut self, index: usize) -> T {
+        assert!(index < self.len, "index is out of bounds");
+        for i in index..self.len - 1 {
+            self.swap(i, i + 1);
+        }
+        self.pop().expect("is not empty")
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        let (f, _) = Self::location(self.len.checked_sub(1)?);
+        self.len -= 1;
+        self.fragments[f].pop()
+    }
+
+    fn swap(&mut self, a: usize, b: usize) {
+        assert!(a < self.len && b < self.len, "index is out of bounds");
+        if a != b {
+            let a = PinnedVec::get_ptr_mut(self, a).expect("is in bounds");
+            let b = PinnedVec::get_ptr_mut(self, b).expect("is in bounds");
+            unsafe { core::ptr::swap_nonoverlapping(a, b, 1) };
+        }
+    }
+
+    fn truncate(&mut self, len: usize) {
+        if len < self.len {
+            for (f, fragment) in self.fragments.iter_mut().enumerate() {
+                fragment.truncate(len.saturating_sub(f * FRAGMENT_LEN).min(FRAGMENT_LEN));
+            }
+            self.len = len;
+        }
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        self.fragments.iter().flatten()
+    }
+
+    fn iter_mut(&mut self) -> Self::IterMut<'_> {
+        self.fragments.iter_mut().flatten()
+    }
+
+    fn iter_rev(&self) -> Self::IterRev<'_> {
+        self.iter().rev()
+    }
+
+    fn iter_mut_rev(&mut self) -> Self::IterMutRev<'_> {
+        self.iter_mut().rev()
+    }
+
+    fn slices<R: RangeBounds<usize>>(&self, range: R) -> Self::SliceIter<'_> {
+        match self.slices_range(&range) {
+            Some([a, b]) => self
+                .fragments
+                .iter()
+                .enumerate()
+                .filter_map(|(f, fragment)| {
+                    let begin = f * FRAGMENT_LEN;
+                    let (x, y) = (a.max(begin), b.min(begin + FRAGMENT_LEN));
+                    (x < y).then(|| &fragment[(x - begin)..(y - begin)])
+                })
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    fn slices_mut<R: RangeBounds<usize>>(&mut self, range: R) -> Self::SliceMutIter<'_> {
+        match self.slices_range(&range) {
+            Some([a, b]) => self
+                .fragments
+                .iter_mut()
+                .enumerate()
+                .filter_map(|(f, fragment)| {
+                    let begin = f * FRAGMENT_LEN;
+                    let (x, y) = (a.max(begin), b.min(begin + FRAGMENT_LEN));
+                    (x < y).then(|| &mut fragment[(x - begin)..(y - begin)])
+                })
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    fn get_ptr(&self, index: usize) -> Option<*const T> {
+        let (f, i) = Self::location(index);
+        self.fragments
+            .get(f)
+            .map(|fragment| unsafe { fragment.as_ptr().add(i) })
+    }
+
+    fn get_ptr_mut(&mut self, index: usize) -> Option<*mut T> {
+        let (f, i) = Self::location(index);
+        self.fragments
+            .get_mut(f)
+            .map(|fragment| unsafe { fragment.as_mut_ptr().add(i) })
+    }
+
+    unsafe fn set_len(&mut self, new_len: usize) {
+        debug_assert!(new_len <= PinnedVec::capacity(self));
+        self.set_fragment_lengths(new_len);
+    }
+
+    fn spare_capacity_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut MaybeUninit<T>> + 'a
+    where
+        T: 'a,
+    {
+        self.fragments
+            .iter_mut()
+            .flat_map(|fragment| fragment.spare_capacity_mut().iter_mut())
+    }
+
+    fn binary_search_by<F>(&self, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        let (mut begin, mut end) = (0, self.len);
+        while begin < end {
+            let mid = begin + (end - begin) / 2;
+            match f(&self[mid]) {
+                Ordering::Less => begin = mid + 1,
+                Ordering::Greater => end = mid,
+                Ordering::Equal => return Ok(mid),
+            }
+        }
+        Err(begin)
+    }
+
+    fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_by(|a, b| a.cmp(b))
+    }
+
+    fn sort_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let mut elements: Vec<_> = self
+            .fragments
+            .iter_mut()
+            .flat_map(|f| f.drain(..))
+            .collect();
+        self.len = 0;
+        elements.sort_by(compare);
+        for x in elements {
+            self.push(x);
+        }
+    }
+
+    fn sort_by_key<K, F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        self.sort_by(|a, b| f(a).cmp(&f(b)))
+    }
+}
+
+/// Maximum number of fragments that a `ConFragVec` created by `into_concurrent` can concurrently grow to.
+pub const CON_FRAG_VEC_MAX_FRAGMENTS: usize = 1024;
+
+impl<T> IntoConcurrentPinnedVec<T> for FragVec<T> {
+    type ConPinnedVec = ConFragVec<T>;
+
+    fn into_concurrent(self) -> Self::ConPinnedVec {
+        let max_fragments = self.fragments.len().max(CON_FRAG_VEC_MAX_FRAGMENTS);
+        ConFragVec::new(self, max_fragments)
+    }
+
+    fn into_concurrent_filled_with<F>(mut self, fill_with: F) -> Self::ConPinnedVec
+    where
+        F: Fn() -> T,
+    {
+        while self.len < PinnedVec::capacity(&self) {
+            self.push(fill_with());
+        }
+        self.into_concurrent()
+    }
+}
+
+/// Concurrent wrapper of the `FragVec` which grows by allocating new fragments;
+/// hence, unlike `ConTestVec`, its `grow_to` actually allocates while other threads might be writing to the existing fragments.
+///
+/// Pointers to the fragments are stored in a table of `max_capacity / FRAGMENT_LEN` slots which is never reallocated by `&self` methods.
+/// Elements which are not converted back by `into_inner` are not dropped; the fragments are deallocated though.
+pub struct ConFragVec<T> {
+    fragments: Vec<AtomicPtr<T>>,
+    capacity: AtomicUsize,
+    growth: std::sync::Mutex<()>,
+}
+
+unsafe impl<T: Send> Send for ConFragVec<T> {}
+
+unsafe impl<T: Send + Sync> Sync for ConFragVec<T> {}
+
+impl<T> ConFragVec<T> {
+    fn new(vec: FragVec<T>, max_fragments: usize) -> Self {
+        let capacity = PinnedVec::capacity(&vec);
+        let mut fragments: Vec<_> = vec
+            .fragments
+            .into_iter()
+            .map(|fragment| {
+                let mut fragment = core::mem::ManuallyDrop::new(fragment);
+                assert_eq!(fragment.capacity(), FRAGMENT_LEN);
+                AtomicPtr::new(fragment.as_mut_ptr())
+            })
+            .collect();
+        fragments.resize_with(max_fragments, || AtomicPtr::new(core::ptr::null_mut()));
+        Self {
+            fragments,
+            capacity: AtomicUsize::new(capacity),
+            growth: std::sync::Mutex::new(()),
+        }
+    }
+
+    fn allocate_fragment() -> *mut T {
+        let mut fragment = core::mem::ManuallyDrop::new(Vec::with_capacity(FRAGMENT_LEN));
+        assert_eq!(fragment.capacity(), FRAGMENT_LEN);
+        fragment.as_mut_ptr()
+    }
+
+    fn ptr(&self, index: usize) -> *mut T {
+        assert!(index < self.capacity(), "index is out of capacity bounds");
+        let (f, i) = FragVec::<T>::location(index);
+        unsafe { self.fragments[f].load(AtomicOrdering::Acquire).add(i) }
+    }
+
+    /// Grows the capacity to at least `new_capacity` by allocating new fragments;
+    /// `init` is called with the pointer of each new fragment before the fragment is published by increasing the capacity.
+    fn grow<F>(&self, new_capacity: usize, init: F) -> Result<usize, PinnedVecGrowthError>
+    where
+        F: Fn(*mut T),
+    {
+        let capacity = self.capacity();
+        match (
+            new_capacity <= capacity,
+            new_capacity <= self.max_capacity(),
+        ) {
+            (true, _) => Ok(capacity),
+            (false, false) => Err(PinnedVecGrowthError::FailedToGrowWhileKeepingElementsPinned),
+            (false, true) => {
+                let _lock = self.growth.lock().unwrap_or_else(|e| e.into_inner());
+                let mut capacity = self.capacity();
+                while capacity < new_capacity {
+                    let ptr = Self::allocate_fragment();
+                    init(ptr);
+                    self.fragments[capacity / FRAGMENT_LEN].store(ptr, AtomicOrdering::Release);
+                    capacity += FRAGMENT_LEN;
+                    self.capacity.store(capacity, AtomicOrdering::Release);
+                }
+                Ok(capacity)
+            }
+        }
+    }
+
+    fn fragment_slices<R: RangeBounds<usize>>(&self, range: &R) -> Vec<(*mut T, usize)> {
+        let [a, b] = vec_range_limits(range, Some(self.capacity()));
+        (0..self.capacity() / FRAGMENT_LEN)
+            .filter_map(|f| {
+                let begin = f * FRAGMENT_LEN;
+                let (x, y) = (a.max(begin), b.min(begin + FRAGMENT_LEN));
+                (x < y).then(|| (self.ptr(x), y - x))
+            })
+            .collect()
+    }
+}
+
+impl<T> Drop for ConFragVec<T> {
+    fn drop(&mut self) {
+        let num_fragments = self.capacity() / FRAGMENT_LEN;
+        for fragment in self.fragments.iter_mut().take(num_fragments) {
+            drop(unsafe { Vec::from_raw_parts(*fragment.get_mut(), 0, FRAGMENT_LEN) });
+        }
+    }
+}
+
+impl<T> ConcurrentPinnedVec<T> for ConFragVec<T> {
+    type P = FragVec<T>;
+
+    unsafe fn into_inner(mut self, len: usize) -> Self::P {
+        let num_fragments = self.capacity() / FRAGMENT_LEN;
+        let table = core::mem::take(&mut self.fragments);
+        let fragments = table
+            .into_iter()
+            .take(num_fragments)
+            .map(|ptr| Vec::from_raw_parts(ptr.into_inner(), 0, FRAGMENT_LEN))
+            .collect();
+        let mut vec = FragVec { fragments, len: 0 };
+        vec.set_fragment_lengths(len);
+        vec
+    }
+
+    unsafe fn clone_with_len(&self, len: usize) -> Self
+    where
+        T: Clone,
+    {
+        let mut vec = FragVec::new();
+        vec.reserve(self.capacity());
+        for x in self.iter(len) {
+            vec.push(x.clone());
+        }
+        Self::new(vec, self.fragments.len())
+    }
+
+    unsafe fn iter<'a>(&'a self, len: usize) -> impl Iterator<Item = &'a T> + 'a
+    where
+        T: 'a,
+    {
+        (0..len).map(move |i| &*self.ptr(i))
+    }
+
+    unsafe fn iter_over_range<'a, R: RangeBounds<usize>>(
+        &'a self,
+        range: R,
+    ) -> impl Iterator<Item = &'a T> + 'a
+    where
+        T: 'a,
+    {
+        let [a, b] = vec_range_limits(&range, Some(self.capacity()));
+        (a..b).map(move |i| &*self.ptr(i))
+    }
+
+    unsafe fn get(&self, index: usize) -> Option<&T> {
+        (index < self.capacity()).then(|| &*self.ptr(index))
+    }
+
+    unsafe fn get_ptr_mut(&self, index: usize) -> *mut T {
+        self.ptr(index)
+    }
+
+    unsafe fn slices_mut<R: RangeBounds<usize>>(
+        &self,
+        range: R,
+    ) -> <Self::P as PinnedVec<T>>::SliceMutIter<'_> {
+        self.fragment_slices(&range)
+            .into_iter()
+            .map(|(ptr, len)| core::slice::from_raw_parts_mut(ptr, len))
+            .collect()
+    }
+
+    fn slices<R: RangeBounds<usize>>(&self, range: R) -> <Self::P as PinnedVec<T>>::SliceIter<'_> {
+        self.fragment_slices(&range)
+            .into_iter()
+            .map(|(ptr, len)| unsafe { core::slice::from_raw_parts(ptr as *const T, len) })
+            .collect()
+    }
+
+    fn max_capacity(&self) -> usize {
+        self.fragments.len() * FRAGMENT_LEN
+    }
+
+    fn capacity(&self) -> usize {
+        self.capacity.load(AtomicOrdering::Acquire)
+    }
+
+    fn grow_to(&self, new_capacity: usize) -> Result<usize, PinnedVecGrowthError> {
+        self.grow(new_capacity, |_| {})
+    }
+
+    fn grow_to_and_fill_with<F>(
+        &self,
+        new_capacity: usize,
+        fill_with: F,
+    ) -> Result<usize, PinnedVecGrowthError>
+    where
+        F: Fn() -> T,
+    {
+        self.grow(new_capacity, |ptr| {
+            for i in 0..FRAGMENT_LEN {
+                unsafe { ptr.add(i).write(fill_with()) };
+            }
+        })
+    }
+
+    fn fill_with<F>(&self, range: core::ops::Range<usize>, fill_with: F)
+    where
+        F: Fn() -> T,
+    {
+        for i in range {
+            unsafe { self.get_ptr_mut(i).write(fill_with()) };
+        }
+    }
+
+    unsafe fn reserve_maximum_concurrent_capacity(
+        &mut self,
+        _len: usize,
+        new_maximum_capacity: usize,
+    ) -> usize {
+        let max_fragments = new_maximum_capacity.div_ceil(FRAGMENT_LEN);
+        if max_fragments > self.fragments.len() {
+            self.fragments
+                .resize_with(max_fragments, || AtomicPtr::new(core::ptr::null_mut()));
+        }
+        self.max_capacity()
+    }
+
+    unsafe fn reserve_maximum_concurrent_capacity_fill_with<F>(
+        &mut self,
+        len: usize,
+        new_maximum_capacity: usize,
+        _: F,
+    ) -> usize
+    where
+        F: Fn() -> T,
+    {
+        // only the fragment table is extended, no fragment is allocated
+        self.reserve_maximum_concurrent_capacity(len, new_maximum_capacity)
+    }
+
+    unsafe fn set_pinned_vec_len(&mut self, _: usize) {
+        // lengths of the fragments are set by `into_inner`
+    }
+
+    unsafe fn iter_mut<'a>(&'a mut self, len: usize) -> impl Iterator<Item = &'a mut T> + 'a
+    where
+        T: 'a,
+    {
+        let vec: &'a Self = self;
+        (0..len).map(move |i| &mut *vec.ptr(i))
+    }
+
+    unsafe fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        (index < self.capacity()).then(|| &mut *self.ptr(index))
+    }
+
+    unsafe fn clear(&mut self, len: usize) {
+        for i in 0..len {
+            self.ptr(i).drop_in_place();
+        }
+    }
+}
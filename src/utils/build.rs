@@ -0,0 +1,92 @@
+use crate::PinnedVec;
+use alloc::vec::Vec;
+
+/// Collects the elements of the `iter` into a new pinned vector of type `P`.
+///
+/// The vector is created by `pseudo_default`.
+/// Then, the lower bound of the `size_hint` of the iterator is used to `reserve` capacity before pushing the elements,
+/// which reduces the number of allocations for dynamic vectors.
+/// For vectors where `reserve` is a no-op, the elements are simply pushed one by one.
+pub fn collect_pinned_with_capacity<P, T, I>(iter: I) -> P
+where
+    P: PinnedVec<T>,
+    I: IntoIterator<Item = T>,
+{
+    let iter = iter.into_iter();
+    let mut vec = P::pseudo_default();
+    vec.reserve(iter.size_hint().0);
+    for x in iter {
+        vec.push(x);
+    }
+    vec
+}
+
+/// Converts the standard `vec` into a new pinned vector of type `P` by moving its elements.
+///
+/// This is equivalent to `collect_pinned_with_capacity(vec)`; i.e., the pinned vector is created by `pseudo_default`,
+/// capacity for `vec.len()` elements is reserved and the elements are pushed one by one.
+/// Note that contiguous fixed capacity vectors which cannot grow after construction must have enough capacity up front;
+/// hence, they might need to be pre-sized through their own constructors rather than relying on this function.
+pub fn from_std_vec<P, T>(vec: Vec<T>) -> P
+where
+    P: PinnedVec<T>,
+{
+    collect_pinned_with_capacity(vec)
+}
+
+/// Creates a new empty pinned vector of type `P` with capacity for at least `capacity` elements.
+///
+/// The vector is created by `pseudo_default` and then `reserve(capacity)` is called on it,
+/// which provides a uniform construction entry point similar to `Vec::with_capacity`.
+/// Note that `reserve` is a no-op for fixed capacity vectors which cannot grow after construction;
+/// for these types, the pseudo default vector is returned as is and the type's own constructor should be used to set the capacity.
+pub fn with_capacity<P, T>(capacity: usize) -> P
+where
+    P: PinnedVec<T>,
+{
+    let mut vec = P::pseudo_default();
+    vec.reserve(capacity);
+    vec
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pinned_vec_tests::testvec::TestVec;
+
+    #[test]
+    fn collect_pinned_with_capacity_empty() {
+        let vec: TestVec<usize> = collect_pinned_with_capacity(0..0);
+        assert!(vec.is_empty());
+    }
+
+    #[test]
+    fn collect_pinned_with_capacity_uses_size_hint() {
+        // TestVec cannot grow once it has elements; collecting succeeds only if capacity is reserved up front
+        let vec: TestVec<usize> = collect_pinned_with_capacity(0..10_000);
+        assert_eq!(vec.num_reserve_calls(), 1);
+        assert!(vec.capacity() >= 10_000);
+        assert_eq!(vec.to_vec(), (0..10_000).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn from_std_vec() {
+        let std_vec: Vec<_> = (0..50).collect();
+        let vec: TestVec<usize> = super::from_std_vec(std_vec.clone());
+        assert_eq!(vec.len(), 50);
+        assert_eq!(vec.to_vec(), std_vec);
+
+        let vec: TestVec<usize> = super::from_std_vec(Vec::new());
+        assert!(vec.is_empty());
+    }
+
+    #[test]
+    fn with_capacity() {
+        for capacity in [0, 1, 42, 1000] {
+            let vec: TestVec<usize> = super::with_capacity(capacity);
+            assert!(vec.is_empty());
+            assert!(vec.capacity() >= capacity);
+            assert_eq!(vec.num_reserve_calls(), 1);
+        }
+    }
+}
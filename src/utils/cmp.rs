@@ -0,0 +1,111 @@
+use crate::PinnedVec;
+use core::cmp::Ordering;
+
+/// Returns whether or not the two pinned vectors `a` and `b` are equal; i.e., they have the same length and equal elements at every position.
+///
+/// The vectors do not need to be of the same `PinnedVec` implementation.
+/// For instance, a `SplitVec` can be compared against a `FixedVec` without materializing any of them.
+pub fn eq_pinned<A: PinnedVec<T>, B: PinnedVec<T>, T: PartialEq>(a: &A, b: &B) -> bool {
+    a.len() == b.len() && a.iter().zip(b.iter()).all(|(x, y)| x == y)
+}
+
+/// Returns whether or not the pinned vector `vec` is equal to the `slice`; i.e., they have the same length and equal elements at every position.
+pub fn eq_slice<P: PinnedVec<T>, T: PartialEq>(vec: &P, slice: &[T]) -> bool {
+    vec.len() == slice.len() && vec.iter().zip(slice.iter()).all(|(x, y)| x == y)
+}
+
+/// Lexicographically compares the two pinned vectors `a` and `b`.
+///
+/// Elements are compared pairwise in order; the first non-equal pair determines the result.
+/// If one vector is a prefix of the other, the shorter vector is considered to be less.
+/// This matches the ordering semantics of slices.
+pub fn cmp_pinned<A: PinnedVec<T>, B: PinnedVec<T>, T: Ord>(a: &A, b: &B) -> Ordering {
+    a.iter().cmp(b.iter())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pinned_vec_tests::testvec::TestVec;
+
+    fn test_vec(values: &[u32]) -> TestVec<u32> {
+        let mut vec = TestVec::new(values.len());
+        vec.extend_from_slice(values);
+        vec
+    }
+
+    #[test]
+    fn eq_pinned_identical() {
+        let a = test_vec(&[1, 2, 3]);
+        let b = test_vec(&[1, 2, 3]);
+        assert!(eq_pinned(&a, &b));
+        assert!(eq_pinned(&b, &a));
+
+        let a = test_vec(&[]);
+        let b = test_vec(&[]);
+        assert!(eq_pinned(&a, &b));
+    }
+
+    #[test]
+    fn eq_pinned_differing() {
+        let a = test_vec(&[1, 2, 3]);
+
+        assert!(!eq_pinned(&a, &test_vec(&[1, 2, 4])));
+        assert!(!eq_pinned(&a, &test_vec(&[1, 2])));
+        assert!(!eq_pinned(&a, &test_vec(&[1, 2, 3, 4])));
+        assert!(!eq_pinned(&a, &test_vec(&[])));
+    }
+
+    #[test]
+    fn eq_slice_identical() {
+        let vec = test_vec(&[1, 2, 3]);
+        assert!(eq_slice(&vec, &[1, 2, 3]));
+
+        let vec = test_vec(&[]);
+        assert!(eq_slice(&vec, &[]));
+    }
+
+    #[test]
+    fn eq_slice_differing() {
+        let vec = test_vec(&[1, 2, 3]);
+        assert!(!eq_slice(&vec, &[1, 2, 4]));
+        assert!(!eq_slice(&vec, &[1, 2]));
+        assert!(!eq_slice(&vec, &[1, 2, 3, 4]));
+        assert!(!eq_slice(&vec, &[]));
+    }
+
+    #[test]
+    fn cmp_pinned_prefix() {
+        let a = test_vec(&[1, 2]);
+        let b = test_vec(&[1, 2, 3]);
+        assert_eq!(cmp_pinned(&a, &b), Ordering::Less);
+        assert_eq!(cmp_pinned(&b, &a), Ordering::Greater);
+
+        let empty = test_vec(&[]);
+        assert_eq!(cmp_pinned(&empty, &a), Ordering::Less);
+        assert_eq!(cmp_pinned(&a, &empty), Ordering::Greater);
+    }
+
+    #[test]
+    fn cmp_pinned_element_difference() {
+        let a = test_vec(&[1, 2, 3]);
+        let b = test_vec(&[1, 3]);
+        assert_eq!(cmp_pinned(&a, &b), Ordering::Less);
+        assert_eq!(cmp_pinned(&b, &a), Ordering::Greater);
+        assert_eq!(
+            cmp_pinned(&a, &b),
+            [1, 2, 3].as_slice().cmp([1, 3].as_slice())
+        );
+    }
+
+    #[test]
+    fn cmp_pinned_equal() {
+        let a = test_vec(&[1, 2, 3]);
+        let b = test_vec(&[1, 2, 3]);
+        assert_eq!(cmp_pinned(&a, &b), Ordering::Equal);
+
+        let a = test_vec(&[]);
+        let b = test_vec(&[]);
+        assert_eq!(cmp_pinned(&a, &b), Ordering::Equal);
+    }
+}
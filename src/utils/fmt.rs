@@ -0,0 +1,49 @@
+use crate::PinnedVec;
+use core::fmt::{Debug, Formatter, Result};
+
+/// Formats the elements of the pinned vector as a list, exactly as a slice would be formatted: `[a, b, c]`.
+///
+/// This method is useful to implement `Debug` for `PinnedVec` implementations with a one-liner:
+///
+/// ```rust ignore
+/// impl<T: Debug> Debug for MyPinnedVec<T> {
+///     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+///         orx_pinned_vec::utils::fmt::debug_pinned(self, f)
+///     }
+/// }
+/// ```
+pub fn debug_pinned<P: PinnedVec<T>, T: Debug>(vec: &P, f: &mut Formatter<'_>) -> Result {
+    f.debug_list().entries(vec.iter()).finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pinned_vec_tests::testvec::TestVec;
+    use alloc::format;
+
+    struct Wrapper<'a>(&'a TestVec<u32>);
+
+    impl Debug for Wrapper<'_> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+            debug_pinned(self.0, f)
+        }
+    }
+
+    #[test]
+    fn debug_pinned_empty() {
+        let vec: TestVec<u32> = TestVec::new(4);
+        assert_eq!(format!("{:?}", Wrapper(&vec)), "[]");
+    }
+
+    #[test]
+    fn debug_pinned_formats_as_slice() {
+        let mut vec = TestVec::new(4);
+        vec.push(1);
+        vec.push(2);
+        vec.push(3);
+
+        assert_eq!(format!("{:?}", Wrapper(&vec)), "[1, 2, 3]");
+        assert_eq!(format!("{:?}", Wrapper(&vec)), format!("{:?}", [1, 2, 3]));
+    }
+}
@@ -0,0 +1,89 @@
+use crate::PinnedVec;
+use core::hash::{Hash, Hasher};
+
+/// Feeds the pinned vector `vec` into the given hasher `state`.
+///
+/// The length of the vector is hashed first, followed by each of its elements in order;
+/// hence, equal contents produce equal hashes across `PinnedVec` implementations.
+///
+/// Note that slices hash their elements with `Hash::hash_slice`, which might write all elements at once.
+/// Therefore, the result equals the hash of a slice with the same contents only for hashers which process the written bytes as a stream, such as FNV.
+///
+/// This method is useful to implement `Hash` for `PinnedVec` implementations so that they can be used as map keys.
+pub fn hash_pinned<P: PinnedVec<T>, T: Hash, H: Hasher>(vec: &P, state: &mut H) {
+    state.write_usize(vec.len());
+    for x in vec.iter() {
+        x.hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pinned_vec_tests::{test_all::tests::JustVec, testvec::TestVec};
+    use alloc::vec::Vec;
+
+    /// Fowler-Noll-Vo hasher, used only to have deterministic hashes in no-std tests.
+    struct FnvHasher(u64);
+
+    impl Default for FnvHasher {
+        fn default() -> Self {
+            Self(0xcbf29ce484222325)
+        }
+    }
+
+    impl Hasher for FnvHasher {
+        fn finish(&self) -> u64 {
+            self.0
+        }
+
+        fn write(&mut self, bytes: &[u8]) {
+            for b in bytes {
+                self.0 ^= *b as u64;
+                self.0 = self.0.wrapping_mul(0x100000001b3);
+            }
+        }
+    }
+
+    fn hash_of<P: PinnedVec<u32>>(vec: &P) -> u64 {
+        let mut hasher = FnvHasher::default();
+        hash_pinned(vec, &mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn hash_pinned_same_across_implementors() {
+        let mut test_vec = TestVec::new(10);
+        let mut just_vec = JustVec(Vec::with_capacity(10));
+        for i in 0..10 {
+            test_vec.push(i * 3);
+            just_vec.push(i * 3);
+        }
+
+        assert_eq!(hash_of(&test_vec), hash_of(&just_vec));
+    }
+
+    #[test]
+    fn hash_pinned_matches_slice_for_streaming_hasher() {
+        // equal to the slice hash only because `FnvHasher` processes the written bytes as a stream
+        let values: Vec<u32> = (0..10).collect();
+        let mut vec = TestVec::new(10);
+        vec.extend_from_slice(&values);
+
+        let mut hasher = FnvHasher::default();
+        values.as_slice().hash(&mut hasher);
+
+        assert_eq!(hash_of(&vec), hasher.finish());
+    }
+
+    #[test]
+    fn hash_pinned_different_contents() {
+        let mut a = TestVec::new(3);
+        a.extend_from_slice(&[1, 2, 3]);
+
+        let mut b = TestVec::new(3);
+        b.extend_from_slice(&[1, 2]);
+
+        assert_ne!(hash_of(&a), hash_of(&b));
+    }
+}
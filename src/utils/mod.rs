@@ -1,2 +1,13 @@
+/// Utility functions to build PinnedVec implementations.
+pub mod build;
+/// Utility functions to compare PinnedVec implementations with each other or with slices.
+pub mod cmp;
+/// Utility functions to implement formatting traits for PinnedVec implementations.
+pub mod fmt;
+/// Utility functions to implement hashing for PinnedVec implementations.
+pub mod hash;
+/// Utility functions to process PinnedVec implementations in parallel.
+#[cfg(feature = "rayon")]
+pub mod parallel;
 /// Utility functions to make slice-like PinnedVec implementations more convenient.
 pub mod slice;
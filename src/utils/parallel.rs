@@ -0,0 +1,43 @@
+use crate::PinnedVec;
+use alloc::vec::Vec;
+use rayon::prelude::*;
+
+/// Creates a parallel iterator over references to the elements of the pinned vector.
+///
+/// The parallel iterator is built on `as_slices`; i.e., each contiguous fragment of the vector is processed as a parallel slice.
+/// Therefore, both contiguous and fragmented vectors are processed in parallel without copying the elements.
+///
+/// The slices are collected into a `Vec` up front, which allocates one entry per fragment.
+/// In return, only the slices are shared among the threads; hence, the vector itself is not required to be `Sync`.
+pub fn par_iter<'a, P, T>(vec: &'a P) -> impl ParallelIterator<Item = &'a T>
+where
+    P: PinnedVec<T>,
+    T: Sync + 'a,
+{
+    let slices: Vec<_> = vec.as_slices().collect();
+    slices.into_par_iter().flat_map(|s| s.par_iter())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pinned_vec_tests::testvec::TestVec;
+
+    #[test]
+    fn par_iter_sum() {
+        let mut vec = TestVec::new(100_000);
+        for i in 0..100_000 {
+            vec.push(i);
+        }
+
+        let par_sum: usize = par_iter(&vec).sum();
+        let seq_sum: usize = vec.iter().sum();
+        assert_eq!(par_sum, seq_sum);
+    }
+
+    #[test]
+    fn par_iter_empty() {
+        let vec = TestVec::<usize>::new(10);
+        assert_eq!(par_iter(&vec).count(), 0);
+    }
+}
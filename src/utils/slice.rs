@@ -89,6 +89,23 @@ pub fn contains_ptr<T>(slice: &[T], element_ptr: *const T) -> bool {
     }
 }
 
+/// Returns whether or not the slices `a` and `b` share any memory.
+/// This method has *O(1)* time complexity.
+///
+/// Empty slices and slices of zero-sized types occupy no memory; hence, they never overlap with any slice.
+pub fn slices_overlap<T>(a: &[T], b: &[T]) -> bool {
+    match a.is_empty() || b.is_empty() || core::mem::size_of::<T>() == 0 {
+        true => false,
+        false => {
+            let a_beg = a.as_ptr() as usize;
+            let a_end = a_beg + core::mem::size_of_val(a);
+            let b_beg = b.as_ptr() as usize;
+            let b_end = b_beg + core::mem::size_of_val(b);
+            a_beg < b_end && b_beg < a_end
+        }
+    }
+}
+
 /// Returns the inclusive being and exclusive end of the given `range`.
 /// The range is bounded by the `vec_len` if it is known and provided.
 ///
@@ -96,6 +113,17 @@ pub fn contains_ptr<T>(slice: &[T], element_ptr: *const T) -> bool {
 ///
 /// Panics if end bound is Unbounded while vec_len is None.
 pub fn vec_range_limits<R: RangeBounds<usize>>(range: &R, vec_len: Option<usize>) -> [usize; 2] {
+    try_vec_range_limits(range, vec_len).expect("Unbounded range without a vec_len")
+}
+
+/// Returns the inclusive being and exclusive end of the given `range`.
+/// The range is bounded by the `vec_len` if it is known and provided.
+///
+/// Returns None if end bound is Unbounded while vec_len is None.
+pub fn try_vec_range_limits<R: RangeBounds<usize>>(
+    range: &R,
+    vec_len: Option<usize>,
+) -> Option<[usize; 2]> {
     use core::ops::Bound::*;
 
     let mut begin = match range.start_bound() {
@@ -107,7 +135,7 @@ pub fn vec_range_limits<R: RangeBounds<usize>>(range: &R, vec_len: Option<usize>
     let mut end = match range.end_bound() {
         Excluded(&b) => b,
         Included(b) => b + 1,
-        Unbounded => vec_len.expect("Unbounded range without a vec_len"),
+        Unbounded => vec_len?,
     };
 
     if end < begin {
@@ -123,7 +151,7 @@ pub fn vec_range_limits<R: RangeBounds<usize>>(range: &R, vec_len: Option<usize>
         }
     }
 
-    [begin, end]
+    Some([begin, end])
 }
 
 #[cfg(test)]
@@ -179,4 +207,76 @@ mod tests {
             assert!(contains_reference(&array, element));
         }
     }
+
+    #[test]
+    fn slices_overlap_adjacent() {
+        let array: Vec<_> = (0..100).collect();
+        assert!(!slices_overlap(&array[0..50], &array[50..100]));
+        assert!(!slices_overlap(&array[50..100], &array[0..50]));
+    }
+
+    #[test]
+    fn slices_overlap_overlapping() {
+        let array: Vec<_> = (0..100).collect();
+        assert!(slices_overlap(&array[0..51], &array[50..100]));
+        assert!(slices_overlap(&array[50..100], &array[0..51]));
+        assert!(slices_overlap(&array[..], &array[..]));
+    }
+
+    #[test]
+    fn slices_overlap_nested() {
+        let array: Vec<_> = (0..100).collect();
+        assert!(slices_overlap(&array[..], &array[40..60]));
+        assert!(slices_overlap(&array[40..60], &array[..]));
+        assert!(slices_overlap(&array[40..60], &array[50..51]));
+    }
+
+    #[test]
+    fn slices_overlap_disjoint() {
+        let array1: Vec<_> = (0..100).collect();
+        let array2: Vec<_> = (0..100).collect();
+        assert!(!slices_overlap(&array1, &array2));
+        assert!(!slices_overlap(&array1[0..10], &array1[20..30]));
+    }
+
+    #[test]
+    fn slices_overlap_empty() {
+        let array: Vec<_> = (0..100).collect();
+        assert!(!slices_overlap(&array[..], &array[50..50]));
+        assert!(!slices_overlap(&array[50..50], &array[..]));
+        assert!(!slices_overlap(&array[50..50], &array[50..50]));
+
+        let empty: &[usize] = &[];
+        assert!(!slices_overlap(empty, empty));
+    }
+
+    #[test]
+    fn slices_overlap_zero_sized() {
+        let array = [(), (), ()];
+        assert!(!slices_overlap(&array[..], &array[..]));
+        assert!(!slices_overlap(&array[0..2], &array[1..3]));
+    }
+
+    #[test]
+    fn try_vec_range_limits_unbounded_without_len() {
+        assert_eq!(try_vec_range_limits(&(..), None), None);
+        assert_eq!(try_vec_range_limits(&(3..), None), None);
+    }
+
+    #[test]
+    fn try_vec_range_limits_some() {
+        assert_eq!(try_vec_range_limits(&(2..5), None), Some([2, 5]));
+        assert_eq!(try_vec_range_limits(&(2..=5), None), Some([2, 6]));
+        assert_eq!(try_vec_range_limits(&(..5), None), Some([0, 5]));
+        assert_eq!(try_vec_range_limits(&(..), Some(10)), Some([0, 10]));
+        assert_eq!(try_vec_range_limits(&(3..), Some(10)), Some([3, 10]));
+        assert_eq!(try_vec_range_limits(&(3..20), Some(10)), Some([3, 10]));
+        assert_eq!(try_vec_range_limits(&(12..20), Some(10)), Some([10, 10]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn vec_range_limits_unbounded_without_len() {
+        vec_range_limits(&(3..), None);
+    }
 }
@@ -3,6 +3,7 @@ use orx_pseudo_default::PseudoDefault;
 use std::{
     cmp::Ordering,
     iter::Rev,
+    mem::MaybeUninit,
     ops::{Bound, Index, IndexMut, RangeBounds},
 };
 
@@ -245,9 +246,17 @@ impl<T> PinnedVec<T> for StdVec<T> {
     }
 
     unsafe fn set_len(&mut self, new_len: usize) {
+        debug_assert!(new_len <= PinnedVec::capacity(self));
         self.0.set_len(new_len)
     }
 
+    fn spare_capacity_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut MaybeUninit<T>> + 'a
+    where
+        T: 'a,
+    {
+        self.0.spare_capacity_mut().iter_mut()
+    }
+
     fn binary_search_by<F>(&self, f: F) -> Result<usize, usize>
     where
         F: FnMut(&T) -> Ordering,
@@ -293,6 +302,12 @@ fn range_end<R: RangeBounds<usize>>(range: &R, vec_len: usize) -> usize {
     }
 }
 
+#[test]
+fn std_vec_capacity_bound() {
+    let vec = StdVec::<usize>::new(42);
+    assert_eq!(vec.capacity_bound(), usize::MAX);
+}
+
 // PINNED ELEMENT TESTS
 
 #[test]
@@ -306,6 +321,17 @@ fn std_vec_extend() {
     pinned_vec_tests::extend(StdVec(Vec::new()), 64 * 1024);
 }
 
+#[test]
+fn std_vec_grow_with_capacity() {
+    pinned_vec_tests::grow(StdVec(Vec::with_capacity(64 * 1024)), 64 * 1024);
+}
+
+#[test]
+#[should_panic]
+fn std_vec_grow() {
+    pinned_vec_tests::grow(StdVec(Vec::new()), 64 * 1024);
+}
+
 #[test]
 fn std_vec_insert_with_capacity() {
     pinned_vec_tests::insert(StdVec(Vec::with_capacity(64 * 1024)), 64 * 1024);